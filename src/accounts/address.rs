@@ -1,6 +1,11 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use ed25519_dalek::Verifier;
 use sha2::{Digest, Sha512Trunc256};
+use subtle::ConstantTimeEq;
+use super::{PublicKey, Signature, BYTES_SIGN_PREFIX};
 use crate::helpers::ToArray;
-use crate::encoding::{base32_decode, base32_encode};
+use crate::encoding::{decode_base32, base32_encode};
 use crate::errors::{AlgorandSdkError, Error};
 
 pub const CHECKSUM_BYTES_LENGTH: usize = 4;
@@ -8,7 +13,7 @@ pub const ADDRESS_BYTES_LENGTH: usize = 32;
 
 pub type AddressBytes = [u8; ADDRESS_BYTES_LENGTH];
 
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Address(AddressBytes);
 
 impl Address {
@@ -24,12 +29,7 @@ impl Address {
   /// 
   /// ```
   pub fn from_string(address_str: &str) -> Result<Address, Error> {
-    let optional_address_with_checksum = base32_decode(address_str);
-    if let None = optional_address_with_checksum {
-      return Err(AlgorandSdkError::InvalidChecksumAddress(String::from(address_str)))?;
-    }
-
-    let address_with_checksum = optional_address_with_checksum.unwrap();
+    let address_with_checksum = decode_base32(address_str)?;
     let address_length = address_with_checksum.len();
     let expected_address_length = CHECKSUM_BYTES_LENGTH + ADDRESS_BYTES_LENGTH;
     if address_length != expected_address_length {
@@ -43,7 +43,7 @@ impl Address {
     let checksum = Sha512Trunc256::default().chain(address_bytes).result();
     let expected_checksum_bytes = &checksum[ADDRESS_BYTES_LENGTH - CHECKSUM_BYTES_LENGTH..];
 
-    if expected_checksum_bytes != checksum_bytes {
+    if !bool::from(expected_checksum_bytes.ct_eq(checksum_bytes)) {
       return Err(AlgorandSdkError::InvalidChecksumAddress(String::from(address_str)))?;
     }
 
@@ -68,6 +68,11 @@ impl Address {
     Address(address_bytes)
   }
 
+  /// Create an `Address` from an ed25519 public key. The address is the public key bytes
+  pub fn from_public_key(public_key: &PublicKey) -> Address {
+    Address(public_key.to_bytes())
+  }
+
   /// Get checksum string representation of Address
   /// 
   /// # Returns
@@ -85,6 +90,18 @@ impl Address {
     base32_encode(address_with_checksum.as_ref())
   }
 
+  /// Verifies a signature produced by [`Account::sign_bytes()`] for this address
+  pub fn verify_bytes(&self, data: &[u8], signature: &Signature) -> bool {
+    let public_key = match PublicKey::from_bytes(&self.0) {
+      Ok(public_key) => public_key,
+      Err(_) => return false,
+    };
+
+    let mut message: Vec<u8> = Vec::from(BYTES_SIGN_PREFIX);
+    message.extend(data);
+    public_key.verify(message.as_ref(), signature).is_ok()
+  }
+
   pub fn as_bytes(&self) -> &[u8] {
     &self.0
   }
@@ -98,6 +115,12 @@ impl Address {
   }
 }
 
+impl From<PublicKey> for Address {
+  fn from(public_key: PublicKey) -> Address {
+    Address::from_public_key(&public_key)
+  }
+}
+
 impl Into<AddressBytes> for Address {
   fn into(self) -> AddressBytes {
     self.into_bytes()
@@ -108,7 +131,8 @@ impl Into<AddressBytes> for Address {
 mod tests {
   use rand::RngCore;
   use rand::rngs::OsRng;
-  use super::{Address, ADDRESS_BYTES_LENGTH};
+  use super::{Address, AlgorandSdkError, ADDRESS_BYTES_LENGTH};
+  use crate::accounts::{Account, PublicKey};
   
 
 fn random_bytes(csprng: &mut OsRng) -> [u8; ADDRESS_BYTES_LENGTH] {
@@ -119,7 +143,7 @@ fn random_bytes(csprng: &mut OsRng) -> [u8; ADDRESS_BYTES_LENGTH] {
 
   #[test]
   fn test_encode_decode() {
-    let mut csprng: OsRng = OsRng::new().unwrap();
+    let mut csprng = OsRng;
     for _ in 0..1000 {
       let address = Address(random_bytes(&mut csprng));
       let address_str = address.to_string();
@@ -140,4 +164,28 @@ fn random_bytes(csprng: &mut OsRng) -> [u8; ADDRESS_BYTES_LENGTH] {
 
     assert_eq!(address.to_string(), expected_value);
   }
+
+  #[test]
+  fn from_string_fails_with_wrong_checksum() {
+    let address_str = "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU";
+    let wrong_checksum_str = "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASA";
+
+    assert_eq!(Address::from_string(address_str).unwrap().to_string(), address_str);
+    let actual_error = Address::from_string(wrong_checksum_str).unwrap_err();
+    if let AlgorandSdkError::InvalidChecksumAddress(_) = actual_error {}
+    else {
+      panic!("Not invalid checksum address")
+    }
+  }
+
+  #[test]
+  fn test_address_from_public_key() {
+    let account = Account::generate();
+    let public_key = PublicKey::from_bytes(account.address.as_bytes()).unwrap();
+
+    let address = Address::from_public_key(&public_key);
+    assert_eq!(address.as_bytes(), public_key.as_bytes());
+    assert_eq!(address, Address::from_fixed_bytes(public_key.to_bytes()));
+    assert_eq!(Address::from(public_key), address);
+  }
 }
\ No newline at end of file
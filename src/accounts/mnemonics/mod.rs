@@ -2,7 +2,11 @@
 //! 
 //! The Mnenmonic is usually used to generate a Seed for creating an Account's Keypair
 
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::format;
 use sha2::{Digest, Sha512Trunc256};
+use subtle::ConstantTimeEq;
 use wordlist::WORDLIST;
 use crate::errors::{AlgorandSdkError, Error};
 
@@ -15,23 +19,36 @@ pub const SEED_BYTES_LENGTH: usize = 32;
 
 /// Generate the seed/key from mnemonic phrase
 /// 
+/// Leading, trailing and repeated whitespace between words are ignored
+/// and words are matched case insensitively
 pub fn seed_from_mnemonic(phrase: &str) -> Result<Vec<u8>, Error> {
-  let words: Vec<&str> = phrase.split(' ')
+  let words: Vec<&str> = phrase.split_whitespace()
     .collect();
-  // NOTE: word_len excludes the checksum from the list
-  let word_len = words.len() - 1;
+  seed_from_mnemonic_words(&words)
+}
 
-  // validate phrase length
-  if word_len != MNEMONIC_PHRASE_WORD_COUNT {
-    return Err(AlgorandSdkError::InvalidPhrase(String::from(phrase)))?;
+/// Generate the seed/key from the words of a mnemonic phrase
+/// 
+/// This is useful when the words are entered individually. Each word is trimmed
+/// and lowercased before it is validated.
+pub fn seed_from_mnemonic_words(words: &[&str]) -> Result<Vec<u8>, Error> {
+  let words: Vec<String> = words.iter()
+    .map(|word| word.trim().to_lowercase())
+    .collect();
+
+  // validate phrase length, including the checksum word
+  if words.len() != MNEMONIC_PHRASE_WORD_COUNT + 1 {
+    Err(AlgorandSdkError::InvalidPhrase(MNEMONIC_PHRASE_WORD_COUNT + 1, words.len()))?;
   }
 
+  // NOTE: word_len excludes the checksum from the list
+  let word_len = words.len() - 1;
   let checksum = words.last().unwrap();
   let mut u11_seed: Vec<u32> = Vec::with_capacity(word_len);
 
   // validate phrase words
   for word in &words[..word_len] {
-    if let Ok(idx) = WORDLIST.binary_search(word) {
+    if let Ok(idx) = WORDLIST.binary_search(&word.as_str()) {
         u11_seed.push(idx as u32);
     } else {
         return Err(AlgorandSdkError::InvalidPhraseWord(word.to_string()))?;
@@ -53,15 +70,32 @@ pub fn seed_from_mnemonic(phrase: &str) -> Result<Vec<u8>, Error> {
   // chop off 0 bytes to get seed entropy
   let seed = &entropy[..(entropy.len() - 1)];
   
-  // validate checksum
+  // validate checksum in constant time since it's derived from the seed
   let computed_checksum = compute_checksum(seed);
-  if computed_checksum != *checksum {
+  if !bool::from(computed_checksum.as_bytes().ct_eq(checksum.as_bytes())) {
     return Err(AlgorandSdkError::InvalidChecksum())?;
   }
 
   Ok(Vec::from(seed))
 }
 
+/// Checks that the phrase is a valid mnemonic without deriving a key from it.
+/// 
+/// It returns the same errors as [`seed_from_mnemonic()`] for a wrong word count,
+/// an unknown word or a wrong checksum
+pub fn validate_phrase(phrase: &str) -> Result<(), Error> {
+  seed_from_mnemonic(phrase)?;
+  Ok(())
+}
+
+/// Returns true if the word is in the mnemonic word list.
+/// 
+/// Like phrases, the word is trimmed and matched case insensitively, so it can
+/// be used to validate words as they are entered
+pub fn is_valid_word(word: &str) -> bool {
+  WORDLIST.binary_search(&word.trim().to_lowercase().as_str()).is_ok()
+}
+
 pub fn mnemonic_from_seed(seed: &[u8]) -> Result<String, Error> {
   if seed.len() != SEED_BYTES_LENGTH {
     return Err(AlgorandSdkError::InvalidSeed())?;
@@ -150,7 +184,7 @@ fn to_byte_array(arr: &[u32]) -> Vec<u8> {
 
 #[cfg(test)]
 mod tests {
-  use super::{mnemonic_from_seed, seed_from_mnemonic, AlgorandSdkError};
+  use super::{mnemonic_from_seed, seed_from_mnemonic, seed_from_mnemonic_words, validate_phrase, is_valid_word, AlgorandSdkError};
 
   #[test]
   fn mnemonic_from_seed_should_pass_for_zero_vector() {
@@ -186,7 +220,7 @@ mod tests {
     mnemonic.push('n');
 
     let actual_error = seed_from_mnemonic(&mnemonic).unwrap_err();
-    if let AlgorandSdkError::InvalidChecksum() = actual_error.downcast_ref().unwrap() { /* everything is okay */ }
+    if let AlgorandSdkError::InvalidChecksum() = actual_error { /* everything is okay */ }
     else {
       assert!(false, "Not invalid phrase word")
     }
@@ -194,12 +228,23 @@ mod tests {
     assert!(true)
   }
 
+  #[test]
+  fn seed_from_mnemonic_checksum_error_is_matched_directly() {
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+
+    match seed_from_mnemonic(mnemonic) {
+      Err(AlgorandSdkError::InvalidChecksum()) => {},
+      other => panic!("Unexpected result {:?}", other),
+    }
+    assert_eq!(seed_from_mnemonic(mnemonic).unwrap_err().to_string(), "Invalid Checksum");
+  }
+
   #[test]
   fn seed_from_mnemonic_should_fail_if_mnemonic_is_invalid() {
     let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon venue abandon abandon abandon abandon abandon abandon abandon abandon abandon invest";
 
     let actual_error = seed_from_mnemonic(&mnemonic).unwrap_err();
-    if let AlgorandSdkError::InvalidChecksum() = actual_error.downcast_ref().unwrap() { /* everything is okay */ }
+    if let AlgorandSdkError::InvalidChecksum() = actual_error { /* everything is okay */ }
     else {
       assert!(false, "Not invalid phrase word")
     }
@@ -210,9 +255,129 @@ mod tests {
     let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon venues abandon abandon abandon abandon abandon abandon abandon abandon abandon invest";
 
     let actual_error = seed_from_mnemonic(&mnemonic).unwrap_err();
-    if let AlgorandSdkError::InvalidPhraseWord(_) = actual_error.downcast_ref().unwrap() {}
+    if let AlgorandSdkError::InvalidPhraseWord(_) = actual_error {}
     else {
       assert!(false, "Not invalid phrase word")
     }
   }
+
+  #[test]
+  fn seed_from_mnemonic_ignores_surrounding_whitespace() {
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon invest ";
+
+    let seed = seed_from_mnemonic(mnemonic).unwrap();
+    assert_eq!(seed, vec![0; 32]);
+  }
+
+  #[test]
+  fn seed_from_mnemonic_words_works() {
+    use rand::Rng;
+    let seed = rand::thread_rng().gen::<[u8; 32]>();
+    let mnemonic = mnemonic_from_seed(&seed).unwrap();
+    let words: Vec<&str> = mnemonic.split(' ').collect();
+
+    assert_eq!(seed_from_mnemonic_words(&words).unwrap(), seed.to_vec());
+  }
+
+  #[test]
+  fn seed_from_mnemonic_works_with_padded_phrase() {
+    use rand::Rng;
+    let seed = rand::thread_rng().gen::<[u8; 32]>();
+    let mnemonic = mnemonic_from_seed(&seed).unwrap();
+    let padded_mnemonic = format!("  {}  ", mnemonic.replace(" ", "  "));
+
+    assert_eq!(seed_from_mnemonic(&padded_mnemonic).unwrap(), seed.to_vec());
+  }
+
+  #[test]
+  fn seed_from_mnemonic_should_fail_with_wrong_word_count() {
+    let checksum_phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon invest";
+    let short_phrase = checksum_phrase.replacen("abandon ", "", 1);
+    let long_phrase = format!("abandon {}", checksum_phrase);
+
+    let actual_error = seed_from_mnemonic(&short_phrase).unwrap_err();
+    match actual_error {
+      AlgorandSdkError::InvalidPhrase(25, 24) => {},
+      other => panic!("Unexpected error {:?}", other),
+    }
+
+    let actual_error = seed_from_mnemonic(&long_phrase).unwrap_err();
+    match actual_error {
+      AlgorandSdkError::InvalidPhrase(25, 26) => {},
+      other => panic!("Unexpected error {:?}", other),
+    }
+  }
+
+  #[test]
+  fn seed_from_mnemonic_should_fail_for_empty_and_single_word_phrases() {
+    for (phrase, word_count) in &[("", 0), ("   ", 0), ("abandon", 1), ("invest ", 1)] {
+      let actual_error = seed_from_mnemonic(phrase).unwrap_err();
+      match actual_error {
+        AlgorandSdkError::InvalidPhrase(25, got) => assert_eq!(got, *word_count),
+        other => panic!("Unexpected error {:?}", other),
+      }
+    }
+
+    let actual_error = seed_from_mnemonic_words(&[]).unwrap_err();
+    match actual_error {
+      AlgorandSdkError::InvalidPhrase(25, 0) => {},
+      other => panic!("Unexpected error {:?}", other),
+    }
+  }
+
+  #[test]
+  fn seed_from_mnemonic_words_should_fail_with_empty_word() {
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon invest";
+    let mut words: Vec<&str> = mnemonic.split(' ').collect();
+    words[3] = " ";
+
+    let actual_error = seed_from_mnemonic_words(&words).unwrap_err();
+    match actual_error {
+      AlgorandSdkError::InvalidPhraseWord(word) => assert_eq!(word, ""),
+      other => panic!("Unexpected error {:?}", other),
+    }
+  }
+
+  #[test]
+  fn seed_from_mnemonic_ignores_case() {
+    use rand::Rng;
+    let seed = rand::thread_rng().gen::<[u8; 32]>();
+    let mnemonic = mnemonic_from_seed(&seed).unwrap();
+    let uppercase_mnemonic = format!("{}\t", mnemonic.to_uppercase().replacen(" ", "   ", 3));
+
+    assert_eq!(seed_from_mnemonic(&uppercase_mnemonic).unwrap(), seed.to_vec());
+  }
+
+  #[test]
+  fn is_valid_word_works() {
+    assert!(is_valid_word("abandon"));
+    assert!(is_valid_word("zoo"));
+    assert!(is_valid_word(" Venue "));
+    assert!(!is_valid_word("venues"));
+    assert!(!is_valid_word(""));
+  }
+
+  #[test]
+  fn validate_phrase_works() {
+    let valid_phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon invest";
+    validate_phrase(valid_phrase).unwrap();
+
+    let actual_error = validate_phrase(&valid_phrase.replacen("abandon", "venues", 1)).unwrap_err();
+    match actual_error {
+      AlgorandSdkError::InvalidPhraseWord(word) => assert_eq!(word, "venues"),
+      other => panic!("Unexpected error {:?}", other),
+    }
+
+    let actual_error = validate_phrase(&valid_phrase.replace("invest", "abandon")).unwrap_err();
+    match actual_error {
+      AlgorandSdkError::InvalidChecksum() => {},
+      other => panic!("Unexpected error {:?}", other),
+    }
+
+    let actual_error = validate_phrase("abandon invest").unwrap_err();
+    match actual_error {
+      AlgorandSdkError::InvalidPhrase(25, 2) => {},
+      other => panic!("Unexpected error {:?}", other),
+    }
+  }
 }
\ No newline at end of file
@@ -1,19 +1,68 @@
 pub mod mnemonics;
 mod address;
 
-use rand::rngs::OsRng;
-use ed25519_dalek::{PUBLIC_KEY_LENGTH, SECRET_KEY_LENGTH};
+use core::fmt;
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::format;
+use rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha512Trunc256};
+use ed25519_dalek::{PUBLIC_KEY_LENGTH, SECRET_KEY_LENGTH, KEYPAIR_LENGTH};
 use ed25519_dalek::ExpandedSecretKey;
 
 pub use ed25519_dalek::{PublicKey, SecretKey, Signature};
 pub use address::{Address, AddressBytes};
 
 use mnemonics::seed_from_mnemonic;
-use crate::errors::{Error};
+use crate::errors::{AlgorandSdkError, Error};
+use crate::encoding::{decode_hex, hex_encode};
+#[cfg(feature = "std")]
+use crate::transaction::{SignedTransaction, Transaction};
 
 pub type PublicKeyBytes = [u8; PUBLIC_KEY_LENGTH];
 pub type SecretKeyBytes = [u8; SECRET_KEY_LENGTH];
 
+/// Domain separation prefix for signing arbitrary bytes, so the signature
+/// can't be reused as a transaction signature
+pub const BYTES_SIGN_PREFIX: &[u8] = b"MX";
+
+/// Domain separation prefix for signing transactions
+pub const TX_SIGN_PREFIX: &[u8] = b"TX";
+
+/// Prefix hashed with the multisig preimage to derive the multisig address
+pub const MULTISIG_ADDRESS_PREFIX: &[u8] = b"MultisigAddr";
+
+/// Minimum balance in micro algos of an account that holds no assets or apps
+pub const MIN_BALANCE: u64 = 100_000;
+/// Minimum balance increase for each asset held and each app opted into or created
+pub const MIN_BALANCE_PER_ASSET: u64 = 100_000;
+pub const MIN_BALANCE_PER_APP: u64 = 100_000;
+/// Minimum balance increase for each entry of an app's state schema,
+/// plus the cost of the entry's type
+pub const SCHEMA_MIN_BALANCE_PER_ENTRY: u64 = 25_000;
+pub const SCHEMA_UINT_MIN_BALANCE: u64 = 3_500;
+pub const SCHEMA_BYTES_MIN_BALANCE: u64 = 25_000;
+
+/// The total number of uints and byte slices in the state schemas of the apps of an account
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SchemaCounts {
+  pub num_uint: u64,
+  pub num_byte_slice: u64,
+}
+
+/// Computes the minimum balance in micro algos of an account holding `num_assets` assets and
+/// `num_apps` apps whose state schemas total `num_schema` entries.
+/// 
+/// A transaction that would drop the account balance below this amount is rejected.
+/// The result saturates at `u64::MAX`, a balance no account can hold, instead of overflowing
+pub fn min_balance(num_assets: u64, num_apps: u64, num_schema: SchemaCounts) -> u64 {
+  MIN_BALANCE
+    .saturating_add(MIN_BALANCE_PER_ASSET.saturating_mul(num_assets))
+    .saturating_add(MIN_BALANCE_PER_APP.saturating_mul(num_apps))
+    .saturating_add((SCHEMA_MIN_BALANCE_PER_ENTRY + SCHEMA_UINT_MIN_BALANCE).saturating_mul(num_schema.num_uint))
+    .saturating_add((SCHEMA_MIN_BALANCE_PER_ENTRY + SCHEMA_BYTES_MIN_BALANCE).saturating_mul(num_schema.num_byte_slice))
+}
+
 /// An account used for signing transactions.
 /// 
 /// An `Account` represents the fully formed account containing
@@ -35,6 +84,8 @@ impl Account {
   /// [`Account::from_key()`] function instead, or if you have a mnemonic phrase
   /// then use the [`Account::from_mnemonic()`] function to create an account.
   /// 
+  /// It panics if the OS entropy source is unavailable, use [`Account::try_generate()`] to handle it
+  /// 
   /// # Example
   /// ```rust
   /// use rust_algorand_sdk::accounts::Account;
@@ -44,9 +95,49 @@ impl Account {
   /// // signature represents the signed bytes
   /// println!("Signature {:?}", signature)
   /// ```
+  #[cfg(feature = "std")]
   pub fn generate() -> Account {
-    let mut csprng: OsRng = OsRng::new().unwrap();
-    let private_key = SecretKey::generate(&mut csprng); 
+    Account::try_generate().expect("OS entropy source should be available to generate an account")
+  }
+
+  /// Generates a random Account like [`Account::generate()`].
+  /// 
+  /// It will return an `EntropyUnavailable` Error if the OS entropy source can't be used,
+  /// as can happen in some embedded or WASM runtimes
+  #[cfg(feature = "std")]
+  pub fn try_generate() -> Result<Account, Error> {
+    // getrandom also supports wasm32-unknown-unknown, through the browser crypto api
+    let mut seed = [0u8; SECRET_KEY_LENGTH];
+    if let Err(err) = getrandom::getrandom(&mut seed) {
+      Err(AlgorandSdkError::EntropyUnavailable(err.to_string()))?;
+    }
+    Account::from_key(&seed)
+  }
+
+  /// Generates `n` random accounts.
+  /// 
+  /// With the `parallel` feature enabled, the accounts are generated in parallel.
+  #[cfg(feature = "std")]
+  pub fn generate_many(n: usize) -> Vec<Account> {
+    #[cfg(feature = "parallel")]
+    {
+      use rayon::prelude::*;
+      (0..n).into_par_iter().map(|_| Account::generate()).collect()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+      (0..n).map(|_| Account::generate()).collect()
+    }
+  }
+
+  /// Generates an account with the keys drawn from `csprng`.
+  /// 
+  /// A seeded generator gives the same account every time, such as for test fixtures.
+  /// [`Account::generate()`] uses the OS entropy source, which should be preferred for real accounts.
+  /// Without the `std` feature, this is how accounts are generated, such as from the RNG of an HSM
+  pub fn generate_with_rng<R: RngCore + CryptoRng>(csprng: &mut R) -> Account {
+    let private_key = SecretKey::generate(csprng); 
     let public_key: PublicKey = (&private_key).into();
     Account {
       address: Address::from_fixed_bytes(public_key.to_bytes()),
@@ -62,7 +153,7 @@ impl Account {
   /// 
   /// # Returns
   /// A `Result` with okay value being an `Account` or with error being 
-  /// a `failure::Error` wrapping the internal error that occurred.
+  /// the `AlgorandSdkError` that occurred.
   pub fn from_mnemonic(mnemonic: &str) -> Result<Account, Error> {
     let seed = seed_from_mnemonic(mnemonic)?;
     Account::from_key(seed.as_ref())
@@ -72,7 +163,9 @@ impl Account {
   /// 
   /// Note: the bytes must be of size [`SEED_BYTES_LENGTH`] which is 32
   pub fn from_key(bytes: &[u8]) -> Result<Account, Error> {
-    let private_key = SecretKey::from_bytes(bytes)?;
+    // the key can only be invalid because of its length
+    let private_key = SecretKey::from_bytes(bytes)
+      .map_err(|_| AlgorandSdkError::WrongSecretKeyByteLength(SECRET_KEY_LENGTH, bytes.len()))?;
     let public_key: PublicKey = (&private_key).into();
     
     Ok(Account {
@@ -82,13 +175,126 @@ impl Account {
     })
   }
 
+  /// Create an account from the 32 seed bytes encoded as hex, such as in hex based key stores.
+  /// 
+  /// It will return an Error if the hex is invalid or doesn't decode to `SEED_BYTES_LENGTH` (32) bytes
+  pub fn from_hex_seed(hex: &str) -> Result<Account, Error> {
+    let seed = decode_hex(hex)?;
+    if seed.len() != SECRET_KEY_LENGTH {
+      Err(AlgorandSdkError::WrongSecretKeyByteLength(SECRET_KEY_LENGTH, seed.len()))?;
+    }
+    Account::from_key(&seed)
+  }
+
+  /// Returns the 32 seed bytes of this account encoded as lowercase hex
+  pub fn to_hex_seed(&self) -> String {
+    hex_encode(self.private_key.as_bytes())
+  }
+
+  /// Create an account from a secret key made up of the seed bytes followed by the public key bytes.
+  /// 
+  /// Note: the bytes must be of size `KEYPAIR_LENGTH` which is 64. It will return an Error
+  /// if the public key derived from the seed doesn't match the public key bytes.
+  pub fn from_secret_key(bytes: &[u8]) -> Result<Account, Error> {
+    if bytes.len() != KEYPAIR_LENGTH {
+      Err(AlgorandSdkError::WrongSecretKeyByteLength(KEYPAIR_LENGTH, bytes.len()))?;
+    }
+
+    let account = Account::from_key(&bytes[..SECRET_KEY_LENGTH])?;
+    if account.public_key.as_bytes() != &bytes[SECRET_KEY_LENGTH..] {
+      Err(AlgorandSdkError::MismatchedPublicKey())?;
+    }
+
+    Ok(account)
+  }
+
+  pub fn public_key(&self) -> PublicKey {
+    self.public_key
+  }
+
+  /// Returns true if `address` is the address of this account
+  pub fn address_matches(&self, address: &Address) -> bool {
+    self.address == *address
+  }
+
+  /// Returns true if this account controls `address`, such as in an authorization check.
+  /// 
+  /// It is the same as [`Account::address_matches()`]
+  pub fn owns(&self, address: &Address) -> bool {
+    self.address_matches(address)
+  }
+
   pub fn sign(&self, message: &[u8]) -> Signature {
     let expanded: ExpandedSecretKey = (&self.private_key).into();
     expanded.sign(&message, &self.public_key)
   }
+
+  /// Signs `message` prefixed with the domain separation `prefix`, such as
+  /// `TX_SIGN_PREFIX` ("TX") or `BYTES_SIGN_PREFIX` ("MX").
+  pub fn sign_with_prefix(&self, prefix: &[u8], message: &[u8]) -> Signature {
+    let mut prefixed_message: Vec<u8> = Vec::from(prefix);
+    prefixed_message.extend(message);
+    self.sign(prefixed_message.as_ref())
+  }
+
+  /// Signs arbitrary data prefixed with `BYTES_SIGN_PREFIX` ("MX").
+  /// 
+  /// Use [`Address::verify_bytes()`] to verify the signature.
+  pub fn sign_bytes(&self, data: &[u8]) -> Signature {
+    self.sign_with_prefix(BYTES_SIGN_PREFIX, data)
+  }
+
+  /// Signs each transaction of a group sent by this account.
+  /// 
+  /// It will return an `EmptyTransactionGroup` Error if `txns` is empty and a `MismatchedGroupId` Error
+  /// if the transactions don't all have the same group id assigned, such as one of them missing it
+  #[cfg(feature = "std")]
+  pub fn sign_group(&self, txns: &[Transaction]) -> Result<Vec<SignedTransaction>, Error> {
+    let group_id = match txns.first() {
+      Some(txn) => txn.group_id(),
+      None => Err(AlgorandSdkError::EmptyTransactionGroup())?,
+    };
+
+    if group_id.is_none() || txns.iter().any(|txn| txn.group_id() != group_id) {
+      Err(AlgorandSdkError::MismatchedGroupId())?;
+    }
+
+    txns.iter().map(|txn| txn.sign(self)).collect()
+  }
+}
+
+impl Clone for Account {
+  fn clone(&self) -> Account {
+    Account {
+      // the bytes come from a valid secret key, so this can't fail
+      private_key: SecretKey::from_bytes(self.private_key.as_bytes()).unwrap(),
+      public_key: self.public_key,
+      address: self.address.clone(),
+    }
+  }
+}
+
+/// Accounts are equal when they have the same public key, such as accounts from the same mnemonic
+impl PartialEq for Account {
+  fn eq(&self, other: &Account) -> bool {
+    self.public_key == other.public_key
+  }
+}
+
+impl Eq for Account {}
+
+/// Only the address is printed. The private key is masked so it doesn't leak into logs
+impl fmt::Debug for Account {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.debug_struct("Account")
+      .field("address", &self.address.to_string())
+      .field("private_key", &"<redacted>")
+      .finish()
+  }
 }
 
 /// A type for representing multisig preimage data
+#[derive(Clone, Debug)]
 pub struct MultisigAccount {
   version: u8,
   threshold: u8,
@@ -96,12 +302,71 @@ pub struct MultisigAccount {
 }
 
 impl MultisigAccount {
-  
+  /// Create a multisig account from its preimage.
+  /// 
+  /// Note: version must be 1 and the threshold must be between 1 and the number of public keys.
+  pub fn new(version: u8, threshold: u8, public_keys: Vec<PublicKey>) -> Result<MultisigAccount, Error> {
+    if version != 1 {
+      Err(AlgorandSdkError::InvalidMultisigAccount(format!("Unsupported version {}", version)))?;
+    }
+
+    if threshold == 0 || public_keys.is_empty() || threshold as usize > public_keys.len() {
+      Err(AlgorandSdkError::InvalidMultisigAccount(format!(
+        "Threshold {} is invalid for {} public keys",
+        threshold,
+        public_keys.len())),
+      )?;
+    }
+
+    Ok(MultisigAccount {
+      version,
+      threshold,
+      public_keys,
+    })
+  }
+
+  pub fn version(&self) -> u8 {
+    self.version
+  }
+
+  pub fn threshold(&self) -> u8 {
+    self.threshold
+  }
+
+  pub fn public_keys(&self) -> &[PublicKey] {
+    &self.public_keys
+  }
+
+  /// Returns the position of `public_key` among the public keys of this multisig account,
+  /// or None if it is not a participant
+  pub fn index_of(&self, public_key: &PublicKey) -> Option<usize> {
+    self.public_keys.iter().position(|key| key == public_key)
+  }
+
+  /// Get the address of the multisig account.
+  /// 
+  /// It is the sha512/256 hash of the `MULTISIG_ADDRESS_PREFIX`, version, threshold and public keys.
+  pub fn address(&self) -> Address {
+    let mut hasher = Sha512Trunc256::default()
+      .chain(MULTISIG_ADDRESS_PREFIX)
+      .chain([self.version, self.threshold]);
+    for public_key in &self.public_keys {
+      hasher = hasher.chain(public_key.as_bytes());
+    }
+
+    let mut address_bytes: AddressBytes = Default::default();
+    address_bytes.copy_from_slice(hasher.result().as_slice());
+    Address::from_fixed_bytes(address_bytes)
+  }
 }
 
 #[cfg(test)]
 mod tests {
-  use super::{Account};
+  use std::collections::HashSet;
+  use rand::SeedableRng;
+  use rand::rngs::StdRng;
+  use super::{Account, Address, AlgorandSdkError, MultisigAccount, PublicKey, SchemaCounts, min_balance, TX_SIGN_PREFIX};
+  use crate::encoding::base64_encode;
 
   #[test]
   fn test_account_generation() {
@@ -109,4 +374,188 @@ mod tests {
     // address should be equal to public key
     assert_eq!(account.address.as_bytes(), account.public_key.to_bytes());
   }
+
+  #[test]
+  fn test_generate_many() {
+    let accounts = Account::generate_many(1000);
+    assert_eq!(accounts.len(), 1000);
+
+    let addresses: HashSet<String> = accounts.iter().map(|account| account.address.to_string()).collect();
+    assert_eq!(addresses.len(), 1000);
+  }
+
+  #[test]
+  fn test_account_clone_and_debug() {
+    let account = Account::generate();
+    let cloned_account = account.clone();
+    assert_eq!(cloned_account.address, account.address);
+    assert_eq!(cloned_account.private_key.as_bytes(), account.private_key.as_bytes());
+
+    let debug_output = format!("{:?}", account);
+    assert!(debug_output.contains(&account.address.to_string()));
+    assert!(!debug_output.contains(&format!("{:?}", account.private_key.as_bytes())));
+    assert!(!debug_output.contains(&base64_encode(account.private_key.as_bytes())));
+  }
+
+  #[test]
+  fn test_account_from_secret_key() {
+    let account = Account::generate();
+    let mut secret_key = account.private_key.to_bytes().to_vec();
+    secret_key.extend(account.public_key.as_bytes());
+
+    let imported_account = Account::from_secret_key(&secret_key).unwrap();
+    assert_eq!(imported_account.address.as_bytes(), account.address.as_bytes());
+
+    // corrupt public key half
+    secret_key[63] ^= 0xFF;
+    let actual_error = Account::from_secret_key(&secret_key).err().unwrap();
+    if let AlgorandSdkError::MismatchedPublicKey() = actual_error {}
+    else {
+      panic!("Not mismatched public key")
+    }
+  }
+
+  #[test]
+  fn test_sign_bytes_and_verify_bytes() {
+    let account = Account::generate();
+    let data = b"hello algorand";
+
+    let signature = account.sign_bytes(data);
+    assert!(account.address.verify_bytes(data, &signature));
+    assert!(!account.address.verify_bytes(b"other data", &signature));
+    assert!(!Account::generate().address.verify_bytes(data, &signature));
+  }
+
+  #[test]
+  fn test_transaction_signature_does_not_verify_as_bytes_signature() {
+    let account = Account::generate();
+    let txn_bytes = b"TXtransaction bytes";
+
+    let signature = account.sign(txn_bytes);
+    assert!(!account.address.verify_bytes(txn_bytes, &signature));
+  }
+
+  #[test]
+  fn test_account_address_matches() {
+    let account = Account::generate();
+    let address = Address::from_string(&account.address.to_string()).unwrap();
+
+    assert_eq!(account.address, address);
+    assert!(account.address_matches(&address));
+    assert!(!account.address_matches(&Account::generate().address));
+  }
+
+  #[test]
+  fn test_accounts_from_same_mnemonic_are_equal() {
+    let mnemonic = "advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor";
+    let account = Account::from_mnemonic(mnemonic).unwrap();
+
+    assert_eq!(account, Account::from_mnemonic(mnemonic).unwrap());
+    assert_eq!(account, account.clone());
+    assert_ne!(account, Account::generate());
+    assert!(account.owns(&Account::from_mnemonic(mnemonic).unwrap().address));
+    assert!(!account.owns(&Account::generate().address));
+  }
+
+  #[test]
+  fn test_multisig_address() {
+    let public_keys = [
+      "DN7MBMCL5JQ3PFUQS7TMX5AH4EEKOBJVDUF4TCV6WERATKFLQF4MQUPZTA",
+      "BFRTECKTOOE7A5LHCF3TTEOH2A7BW46IYT2SX5VP6ANKEXHZYJY77SJTVM",
+      "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU",
+    ].iter()
+      .map(|address| PublicKey::from_bytes(Address::from_string(address).unwrap().as_bytes()).unwrap())
+      .collect();
+
+    let multisig_account = MultisigAccount::new(1, 2, public_keys).unwrap();
+    assert_eq!(multisig_account.address().to_string(), "RWJLJCMQAFZ2ATP2INM2GZTKNL6OULCCUBO5TQPXH3V2KR4AG7U5UA5JNM");
+  }
+
+  #[test]
+  fn test_multisig_account_rejects_invalid_threshold() {
+    let public_keys = vec![Account::generate().public_key, Account::generate().public_key];
+
+    assert!(MultisigAccount::new(1, 3, public_keys.clone()).is_err());
+    assert!(MultisigAccount::new(1, 0, public_keys.clone()).is_err());
+    assert!(MultisigAccount::new(2, 1, public_keys).is_err());
+  }
+
+  #[test]
+  fn test_try_generate() {
+    let account = Account::try_generate().unwrap();
+    assert_ne!(account.address, Account::try_generate().unwrap().address);
+  }
+
+  #[test]
+  fn test_generate_with_seeded_rng_is_deterministic() {
+    let first = Account::generate_with_rng(&mut StdRng::seed_from_u64(7));
+    let second = Account::generate_with_rng(&mut StdRng::seed_from_u64(7));
+    assert_eq!(first.address, second.address);
+    assert_eq!(first.to_hex_seed(), second.to_hex_seed());
+
+    let other = Account::generate_with_rng(&mut StdRng::seed_from_u64(8));
+    assert_ne!(other.address, first.address);
+  }
+
+  #[test]
+  fn test_hex_seed_round_trips() {
+    let hex_seed = "0101010101010101010101010101010101010101010101010101010101010101";
+    let account = Account::from_hex_seed(hex_seed).unwrap();
+    assert_eq!(account.address, Account::from_key(&[1; 32]).unwrap().address);
+    assert_eq!(account.to_hex_seed(), hex_seed);
+    assert_eq!(Account::from_hex_seed(&hex_seed.to_uppercase()).unwrap().address, account.address);
+
+    let generated = Account::generate();
+    assert_eq!(Account::from_hex_seed(&generated.to_hex_seed()).unwrap().address, generated.address);
+  }
+
+  #[test]
+  fn test_from_hex_seed_fails_with_invalid_length() {
+    match Account::from_hex_seed("010101").unwrap_err() {
+      AlgorandSdkError::WrongSecretKeyByteLength(32, 3) => {},
+      other => panic!("Unexpected error {:?}", other),
+    }
+    match Account::from_hex_seed("0101010").unwrap_err() {
+      AlgorandSdkError::InvalidEncoding(_, _) => {},
+      other => panic!("Unexpected error {:?}", other),
+    }
+  }
+
+  #[test]
+  fn test_min_balance() {
+    assert_eq!(min_balance(0, 0, SchemaCounts::default()), 100000);
+    assert_eq!(min_balance(1, 0, SchemaCounts::default()), 200000);
+    assert_eq!(min_balance(3, 0, SchemaCounts::default()), 400000);
+    assert_eq!(min_balance(0, 1, SchemaCounts { num_uint: 1, num_byte_slice: 1 }), 278500);
+    assert_eq!(min_balance(2, 2, SchemaCounts { num_uint: 4, num_byte_slice: 2 }), 714000);
+  }
+
+  #[test]
+  fn test_min_balance_saturates_instead_of_overflowing() {
+    assert_eq!(min_balance(u64::MAX, 0, SchemaCounts::default()), u64::MAX);
+    assert_eq!(min_balance(1, 1, SchemaCounts { num_uint: u64::MAX / 2, num_byte_slice: u64::MAX / 2 }), u64::MAX);
+  }
+
+  #[test]
+  fn test_multisig_account_index_of() {
+    let accounts: Vec<Account> = (0..3).map(|_| Account::generate()).collect();
+    let public_keys: Vec<PublicKey> = accounts.iter().map(|account| account.public_key()).collect();
+    let multisig_account = MultisigAccount::new(1, 2, public_keys).unwrap();
+
+    assert_eq!(multisig_account.index_of(&accounts[1].public_key()), Some(1));
+    assert_eq!(multisig_account.index_of(&Account::generate().public_key()), None);
+  }
+
+  #[test]
+  fn test_sign_with_prefix() {
+    let account = Account::generate();
+    let message = b"message";
+
+    let mut prefixed_message = b"TX".to_vec();
+    prefixed_message.extend(message);
+    assert_eq!(
+      account.sign_with_prefix(TX_SIGN_PREFIX, message).to_bytes().to_vec(),
+      account.sign(&prefixed_message).to_bytes().to_vec(),
+    );
+  }
 }
\ No newline at end of file
@@ -0,0 +1,984 @@
+use std::cmp::min;
+use std::thread::sleep;
+use std::time::Duration;
+use serde::{de, Deserialize, Deserializer};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use reqwest::blocking::{Client, RequestBuilder};
+use super::{deserialize_address, deserialize_base64, deserialize_optional_address, is_transient, send_authenticated, send_with_retries, ClientConfig, ALGOD_API_TOKEN_HEADER};
+use crate::accounts::{Account, Address};
+use crate::encoding::{decode_base64, rmp_decode};
+use crate::errors::{AlgorandSdkError, Error};
+use crate::transaction::{last_valid_round, MicroAlgos, PaymentTransactionInput, Round, SignedTransaction, SuggestedParams, Transaction, MAX_TX_GROUP_SIZE, MAX_VALIDITY_WINDOW};
+
+/// Delay before the first retry of a block follower after a transient error.
+/// It doubles after each failed retry up to `FOLLOW_MAX_RETRY_DELAY`
+pub const FOLLOW_INITIAL_RETRY_DELAY: Duration = Duration::from_millis(100);
+pub const FOLLOW_MAX_RETRY_DELAY: Duration = Duration::from_secs(10);
+
+/// Client for the v2 REST api of an algod node.
+///
+/// # Example
+/// ```rust,no_run
+/// use rust_algorand_sdk::client::AlgodClient;
+///
+/// let client = AlgodClient::new("http://localhost:4001", "algod-api-token");
+/// let asset_info = client.asset_information(1234).unwrap();
+/// println!("{} has {} decimals", asset_info.params.creator.to_string(), asset_info.params.decimals);
+/// ```
+pub struct AlgodClient {
+  address: String,
+  token: String,
+  http_client: Client,
+  config: ClientConfig,
+}
+
+impl AlgodClient {
+  /// Creates a client for the node at `address`, authenticated with the algod api `token`.
+  ///
+  /// Failed requests are not retried, use `with_config` or [`AlgodClientBuilder`] to configure retries and timeouts
+  pub fn new(address: &str, token: &str) -> AlgodClient {
+    AlgodClient {
+      address: address.trim_end_matches('/').into(),
+      token: token.into(),
+      http_client: Client::new(),
+      config: ClientConfig::default(),
+    }
+  }
+
+  /// Creates a client for the node at `address` with the timeout and retry policy of `config`
+  pub fn with_config(address: &str, token: &str, config: ClientConfig) -> Result<AlgodClient, Error> {
+    Ok(AlgodClient {
+      address: address.trim_end_matches('/').into(),
+      token: token.into(),
+      http_client: config.http_client()?,
+      config,
+    })
+  }
+
+  /// Gets the balance and asset holdings of the account at `address`
+  pub fn account_information(&self, address: &Address) -> Result<AccountInfo, Error> {
+    self.get_json(&format!("/v2/accounts/{}", address.to_string()))
+  }
+
+  /// Gets the parameters of the asset with id `asset_id`
+  pub fn asset_information(&self, asset_id: u64) -> Result<AssetInfo, Error> {
+    self.get_json(&format!("/v2/assets/{}", asset_id))
+  }
+
+  /// Gets the programs, state schemas and global state of the application with id `app_id`
+  pub fn application_information(&self, app_id: u64) -> Result<ApplicationInfo, Error> {
+    self.get_json(&format!("/v2/applications/{}", app_id))
+  }
+
+  /// Gets the params to build a transaction valid for `MAX_VALIDITY_WINDOW` (1000) rounds
+  /// from the last round of the node.
+  ///
+  /// The suggested fee per byte is 0 when the network isn't congested, then the minimum fee is used as a flat fee
+  /// else it is kept in `min_fee`, see [`SuggestedParams::fee_for()`]
+  pub fn suggested_params(&self) -> Result<SuggestedParams, Error> {
+    let params: TransactionParams = self.get_json("/v2/transactions/params")?;
+    let (fee, is_flat_fee) = if params.fee == 0 { (params.min_fee, true) } else { (params.fee, false) };
+    Ok(SuggestedParams {
+      fee: MicroAlgos(fee),
+      first_round: params.last_round,
+      last_round: last_valid_round(Round(params.last_round), MAX_VALIDITY_WINDOW)?.into(),
+      genesis_id: params.genesis_id,
+      genesis_hash: params.genesis_hash.into(),
+      is_flat_fee,
+      min_fee: MicroAlgos(params.min_fee),
+    })
+  }
+
+  /// Builds a payment of `amount` micro algos from `from` to the address `to` with the
+  /// [`suggested_params`](AlgodClient::suggested_params) of the node and signs it with `from`.
+  ///
+  /// # Example
+  /// ```rust,no_run
+  /// use rust_algorand_sdk::accounts::Account;
+  /// use rust_algorand_sdk::client::AlgodClient;
+  ///
+  /// let client = AlgodClient::new("http://localhost:4001", "algod-api-token");
+  /// let sender = Account::generate();
+  /// let signed_txn = client.payment(&sender, "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU", 1000).unwrap();
+  /// println!("Signed transaction {}", signed_txn.txn_id);
+  /// ```
+  pub fn payment(&self, from: &Account, to: &str, amount: u64) -> Result<SignedTransaction, Error> {
+    let params = self.suggested_params()?;
+    let mut txn = Transaction::from_input(PaymentTransactionInput {
+      from: from.address.to_string(),
+      fee: params.fee,
+      first_round: params.first_round,
+      last_round: params.last_round,
+      note: None,
+      genesis_id: params.genesis_id.clone(),
+      genesis_hash: params.genesis_hash.clone(),
+      is_flat_fee: params.is_flat_fee,
+      to: to.into(),
+      amount: MicroAlgos(amount),
+      close_remainder_to: None,
+      allow_self_close: false,
+    })?;
+    txn.header.fee = params.fee_for(&txn)?;
+    txn.sign(from)
+  }
+
+  /// Gets the current status of the node
+  pub fn status(&self) -> Result<NodeStatus, Error> {
+    self.get_json("/v2/status")
+  }
+
+  /// Waits until the node has passed `round`, or for about a minute, and then gets its status
+  pub fn status_after_block(&self, round: u64) -> Result<NodeStatus, Error> {
+    self.get_json(&format!("/v2/status/wait-for-block-after/{}", round))
+  }
+
+  /// Gets the block of `round`
+  pub fn block(&self, round: u64) -> Result<Block, Error> {
+    let response: BlockResponse = self.get_json(&format!("/v2/blocks/{}?format=json", round))?;
+    Ok(response.block)
+  }
+
+  /// Follows the chain from `start_round`, yielding each block once the node has it.
+  ///
+  /// The iterator never ends. Transient errors, which are network errors and 5xx responses,
+  /// are retried with exponential backoff; other errors are yielded and the round is tried again
+  /// on the next call.
+  ///
+  /// # Example
+  /// ```rust,no_run
+  /// use rust_algorand_sdk::client::AlgodClient;
+  ///
+  /// let client = AlgodClient::new("http://localhost:4001", "algod-api-token");
+  /// for block in client.follow_blocks(1000).take(10) {
+  ///   let block = block.unwrap();
+  ///   println!("Round {} has {} transactions", block.round, block.transactions.len());
+  /// }
+  /// ```
+  pub fn follow_blocks(&self, start_round: u64) -> BlockFollower<'_> {
+    BlockFollower {
+      client: self,
+      next_round: start_round,
+    }
+  }
+
+  /// Gets at most `max` transactions from the pool of pending transactions of the node.
+  /// A `max` of 0 returns all of them
+  pub fn pending_transactions(&self, max: u64) -> Result<PendingTransactions, Error> {
+    self.get_msgpack(&format!("/v2/transactions/pending?max={}&format=msgpack", max))
+  }
+
+  /// Gets at most `max` pending transactions sent or received by `address`.
+  /// A `max` of 0 returns all of them
+  pub fn pending_transactions_by_address(&self, address: &Address, max: u64) -> Result<PendingTransactions, Error> {
+    self.get_msgpack(&format!(
+      "/v2/accounts/{}/transactions/pending?max={}&format=msgpack",
+      address.to_string(),
+      max,
+    ))
+  }
+
+  /// Sends the signed transactions of an atomic group, concatenated in order, to the network.
+  ///
+  /// # Returns
+  /// A `Result` with okay value being the id of the first transaction.
+  /// It will return an Error if the group is empty, has more than `MAX_TX_GROUP_SIZE` (16) transactions
+  /// or the transactions don't all have the same group id assigned
+  pub fn send_raw_transaction_group(&self, signed_txns: &[SignedTransaction]) -> Result<String, Error> {
+    let group = match signed_txns.first() {
+      Some(signed_txn) => signed_txn.group_id(),
+      None => Err(AlgorandSdkError::EmptyTransactionGroup())?,
+    };
+    if signed_txns.len() > MAX_TX_GROUP_SIZE {
+      Err(AlgorandSdkError::TooManyTransactionsInGroup {
+        max: MAX_TX_GROUP_SIZE,
+        got: signed_txns.len(),
+      })?;
+    }
+    if group.is_none() || signed_txns.iter().any(|signed_txn| signed_txn.group_id() != group) {
+      Err(AlgorandSdkError::MismatchedGroupId())?;
+    }
+
+    let mut bytes: Vec<u8> = Vec::new();
+    for signed_txn in signed_txns {
+      bytes.extend(signed_txn.encode()?);
+    }
+    let request = self.http_client.post(format!("{}/v2/transactions", self.address))
+      .header("Content-Type", "application/x-binary")
+      .body(bytes);
+    // not retried, as the node may have accepted the group before failing
+    let response: SentTransaction = send_authenticated(request, ALGOD_API_TOKEN_HEADER, &self.token)?.json()?;
+    Ok(response.tx_id)
+  }
+
+  fn get_msgpack<T: DecodedResponse>(&self, path: &str) -> Result<T, Error> {
+    let request = self.http_client.get(format!("{}{}", self.address, path));
+    let bytes = self.send(request)?.bytes()?;
+    let mut response: T = rmp_decode(&bytes)?;
+    response.after_decode()?;
+    Ok(response)
+  }
+
+  fn get_json<T: DeserializeOwned>(&self, path: &str) -> Result<T, Error> {
+    let request = self.http_client.get(format!("{}{}", self.address, path));
+    Ok(self.send(request)?.json()?)
+  }
+
+  fn send(&self, request: RequestBuilder) -> Result<reqwest::blocking::Response, Error> {
+    send_with_retries(request, ALGOD_API_TOKEN_HEADER, &self.token, &self.config)
+  }
+}
+
+/// Builder of an [`AlgodClient`] with request timeouts and retries.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use rust_algorand_sdk::client::AlgodClientBuilder;
+///
+/// let client = AlgodClientBuilder::new("http://localhost:4001", "algod-api-token")
+///   .timeout(Duration::from_secs(10))
+///   .max_retries(3)
+///   .build()
+///   .unwrap();
+/// ```
+pub struct AlgodClientBuilder {
+  address: String,
+  token: String,
+  config: ClientConfig,
+}
+
+impl AlgodClientBuilder {
+  pub fn new(address: &str, token: &str) -> AlgodClientBuilder {
+    AlgodClientBuilder {
+      address: address.into(),
+      token: token.into(),
+      config: ClientConfig::default(),
+    }
+  }
+
+  /// Maximum duration of each request, including reading the response.
+  ///
+  /// [`AlgodClient::status_after_block()`] waits for about a minute, so a shorter timeout makes it fail
+  pub fn timeout(mut self, timeout: Duration) -> AlgodClientBuilder {
+    self.config.timeout = timeout;
+    self
+  }
+
+  /// Number of times a GET request is sent again after a network error, a 429 or a 5xx response
+  pub fn max_retries(mut self, max_retries: u32) -> AlgodClientBuilder {
+    self.config.max_retries = max_retries;
+    self
+  }
+
+  /// Delay before the first retry, `RETRY_INITIAL_DELAY` by default. It doubles after each retry
+  pub fn retry_backoff(mut self, retry_backoff: Duration) -> AlgodClientBuilder {
+    self.config.retry_backoff = retry_backoff;
+    self
+  }
+
+  pub fn build(self) -> Result<AlgodClient, Error> {
+    AlgodClient::with_config(&self.address, &self.token, self.config)
+  }
+}
+
+/// Iterator over the blocks of the chain, created by [`AlgodClient::follow_blocks()`]
+pub struct BlockFollower<'a> {
+  client: &'a AlgodClient,
+  next_round: u64,
+}
+
+impl<'a> BlockFollower<'a> {
+  fn fetch_next_block(&self) -> Result<Block, Error> {
+    // the wait times out before the round is reached when the chain is stalled
+    loop {
+      let status = self.client.status_after_block(self.next_round.saturating_sub(1))?;
+      if status.last_round >= self.next_round {
+        return self.client.block(self.next_round);
+      }
+    }
+  }
+}
+
+impl<'a> Iterator for BlockFollower<'a> {
+  type Item = Result<Block, Error>;
+
+  fn next(&mut self) -> Option<Result<Block, Error>> {
+    let mut retry_delay = FOLLOW_INITIAL_RETRY_DELAY;
+    loop {
+      match self.fetch_next_block() {
+        Ok(block) => {
+          self.next_round += 1;
+          return Some(Ok(block));
+        },
+        Err(ref error) if is_transient(error) => {
+          sleep(retry_delay);
+          retry_delay = min(retry_delay * 2, FOLLOW_MAX_RETRY_DELAY);
+        },
+        Err(error) => return Some(Err(error)),
+      }
+    }
+  }
+}
+
+// Implemented by msgpack responses that need fixing up after they are decoded
+trait DecodedResponse: DeserializeOwned {
+  fn after_decode(&mut self) -> Result<(), Error> {
+    Ok(())
+  }
+}
+
+/// Transactions in the pool of pending transactions of a node
+#[derive(Debug, Deserialize)]
+pub struct PendingTransactions {
+  #[serde(rename = "top-transactions")]
+  pub top_transactions: Vec<SignedTransaction>,
+
+  /// Total number of transactions in the pool, which can be more than the transactions returned
+  #[serde(rename = "total-transactions")]
+  pub total_transactions: u64,
+}
+
+impl DecodedResponse for PendingTransactions {
+  // transaction ids are not encoded, so they are recomputed
+  fn after_decode(&mut self) -> Result<(), Error> {
+    for signed_txn in self.top_transactions.iter_mut() {
+      signed_txn.txn_id = signed_txn.compute_txn_id()?;
+    }
+    Ok(())
+  }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct TransactionParams {
+  /// Fee per byte
+  fee: u64,
+  genesis_hash: String,
+  genesis_id: String,
+  last_round: u64,
+  min_fee: u64,
+}
+
+#[derive(Deserialize)]
+struct SentTransaction {
+  #[serde(rename = "txId")]
+  tx_id: String,
+}
+
+/// The status of a node
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct NodeStatus {
+  /// Last round the node has a block of
+  pub last_round: u64,
+  pub last_version: String,
+  /// Nanoseconds since the node got the block of `last_round`
+  pub time_since_last_round: u64,
+  /// Nanoseconds the node has been catching up, 0 when it is synced
+  #[serde(default)]
+  pub catchup_time: u64,
+}
+
+/// A block as returned by algod
+#[derive(Debug, Deserialize)]
+pub struct Block {
+  #[serde(rename = "rnd", default)]
+  pub round: u64,
+  /// Unix timestamp in seconds
+  #[serde(rename = "ts", default)]
+  pub timestamp: u64,
+  #[serde(rename = "gen")]
+  pub genesis_id: String,
+  /// Base64 encoded genesis hash
+  #[serde(rename = "gh")]
+  pub genesis_hash: String,
+  #[serde(rename = "prev", default)]
+  pub previous_block_hash: Option<String>,
+  /// Signed transactions of the block, as encoded in the json response
+  #[serde(rename = "txns", default)]
+  pub transactions: Vec<Value>,
+}
+
+#[derive(Deserialize)]
+struct BlockResponse {
+  block: Block,
+}
+
+/// The balance and asset holdings of an account
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct AccountInfo {
+  #[serde(deserialize_with = "deserialize_address")]
+  pub address: Address,
+  pub amount: MicroAlgos,
+  #[serde(default)]
+  pub min_balance: MicroAlgos,
+  pub round: u64,
+  pub status: String,
+  #[serde(default)]
+  pub assets: Vec<AssetHolding>,
+}
+
+impl AccountInfo {
+  /// Returns the holding of the asset with id `asset_id`, None if the account didn't opt in to it
+  pub fn asset_balance(&self, asset_id: u64) -> Option<&AssetHolding> {
+    self.assets.iter().find(|holding| holding.asset_id == asset_id)
+  }
+}
+
+/// The units of an asset held by an account
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct AssetHolding {
+  pub asset_id: u64,
+  pub amount: u64,
+  pub is_frozen: bool,
+}
+
+/// An asset and its parameters
+#[derive(Debug, Deserialize)]
+pub struct AssetInfo {
+  pub index: u64,
+  pub params: AssetInfoParams,
+}
+
+/// The parameters of an asset as returned by algod.
+///
+/// The manager, reserve, freeze and clawback addresses are None when not set
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct AssetInfoParams {
+  #[serde(deserialize_with = "deserialize_address")]
+  pub creator: Address,
+  pub total: u64,
+  pub decimals: u32,
+  pub unit_name: Option<String>,
+  pub name: Option<String>,
+  pub url: Option<String>,
+  #[serde(default)]
+  pub default_frozen: bool,
+  #[serde(default, deserialize_with = "deserialize_optional_address")]
+  pub manager: Option<Address>,
+  #[serde(default, deserialize_with = "deserialize_optional_address")]
+  pub reserve: Option<Address>,
+  #[serde(default, deserialize_with = "deserialize_optional_address")]
+  pub freeze: Option<Address>,
+  #[serde(default, deserialize_with = "deserialize_optional_address")]
+  pub clawback: Option<Address>,
+}
+
+/// An application and its parameters
+#[derive(Debug, Deserialize)]
+pub struct ApplicationInfo {
+  pub id: u64,
+  pub params: ApplicationParams,
+}
+
+/// The parameters of an application as returned by algod
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ApplicationParams {
+  #[serde(deserialize_with = "deserialize_address")]
+  pub creator: Address,
+  #[serde(deserialize_with = "deserialize_base64")]
+  pub approval_program: Vec<u8>,
+  #[serde(deserialize_with = "deserialize_base64")]
+  pub clear_state_program: Vec<u8>,
+  #[serde(default)]
+  pub global_state: Vec<TealKeyValue>,
+  #[serde(default)]
+  pub global_state_schema: StateSchema,
+  #[serde(default)]
+  pub local_state_schema: StateSchema,
+}
+
+impl ApplicationParams {
+  /// Returns the global state value stored at `key`, None if the key is not set
+  pub fn global_value(&self, key: &[u8]) -> Option<&TealValue> {
+    self.global_state.iter()
+      .find(|key_value| key_value.key == key)
+      .map(|key_value| &key_value.value)
+  }
+}
+
+/// The number of integers and byte slices an application can store
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct StateSchema {
+  #[serde(default)]
+  pub num_uint: u64,
+  #[serde(default)]
+  pub num_byte_slice: u64,
+}
+
+/// A key of the state of an application and its value
+#[derive(Debug, Deserialize)]
+pub struct TealKeyValue {
+  #[serde(deserialize_with = "deserialize_base64")]
+  pub key: Vec<u8>,
+  pub value: TealValue,
+}
+
+/// A value of the state of an application
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TealValue {
+  Bytes(Vec<u8>),
+  Uint(u64),
+}
+
+impl<'de> Deserialize<'de> for TealValue {
+  // algod sends both fields and tells which one is set with the type, 1 for bytes and 2 for uint
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<TealValue, D::Error> {
+    #[derive(Deserialize)]
+    struct RawTealValue {
+      #[serde(rename = "type")]
+      value_type: u64,
+      #[serde(default)]
+      bytes: String,
+      #[serde(default)]
+      uint: u64,
+    }
+
+    let raw = RawTealValue::deserialize(deserializer)?;
+    match raw.value_type {
+      1 => decode_base64(&raw.bytes).map(TealValue::Bytes).map_err(de::Error::custom),
+      2 => Ok(TealValue::Uint(raw.uint)),
+      others => Err(de::Error::custom(format!("unknown teal value type {}", others))),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde::Serialize;
+  use std::time::{Duration, Instant};
+  use super::{AlgodClient, AlgodClientBuilder, TealValue};
+  use crate::accounts::{Account, Address};
+  use crate::client::mock::{MockResponse, MockServer};
+  use crate::encoding::{base64_decode, rmp_encode};
+  use crate::errors::AlgorandSdkError;
+  use crate::transaction::{assign_group_id, PaymentTransactionInput, SignedTransaction, Transaction, MicroAlgos};
+
+  fn signed_payment(sender: &Account, amount: u64) -> SignedTransaction {
+    payment(sender, amount).sign(sender).unwrap()
+  }
+
+  fn payment(sender: &Account, amount: u64) -> Transaction {
+    Transaction::from_input(PaymentTransactionInput {
+      from: sender.address.to_string(),
+      to: Account::generate().address.to_string(),
+      fee: MicroAlgos(1000),
+      amount: MicroAlgos(amount),
+      first_round: 12466,
+      last_round: 13466,
+      note: None,
+      close_remainder_to: None,
+      allow_self_close: false,
+      genesis_id: "devnet-v33.0".into(),
+      genesis_hash: "JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI=".into(),
+      is_flat_fee: true,
+    }).unwrap()
+  }
+
+  const ASSET_RESPONSE: &str = r#"{
+    "index": 1234,
+    "params": {
+      "clawback": "PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI",
+      "creator": "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU",
+      "decimals": 2,
+      "default-frozen": false,
+      "manager": "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU",
+      "name": "Test Token",
+      "name-b64": "VGVzdCBUb2tlbg==",
+      "reserve": "BH55E5RMBD4GYWXGX5W5PJ5JAHPGM5OXKDQH5DC4O2MGI7NW4H6VOE4CP4",
+      "total": 1000000,
+      "unit-name": "TST",
+      "unit-name-b64": "VFNU",
+      "url": "https://example.com/token"
+    }
+  }"#;
+
+  fn params_response(fee: u64) -> MockResponse {
+    MockResponse::json(&format!(r#"{{
+      "consensus-version": "https://github.com/algorandfoundation/specs/tree/abd3d4823c6f77349fc04c3af7b1e99fe4df699f",
+      "fee": {},
+      "genesis-hash": "SGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiI=",
+      "genesis-id": "testnet-v1.0",
+      "last-round": 20000,
+      "min-fee": 2000
+    }}"#, fee))
+  }
+
+  #[test]
+  fn test_payment_uses_suggested_params() {
+    let server = MockServer::start(vec![params_response(0), params_response(20)]);
+    let client = AlgodClient::new(&server.address, "token");
+    let sender = Account::generate();
+    let receiver = Account::generate();
+
+    let signed_txn = client.payment(&sender, &receiver.address.to_string(), 5000).unwrap();
+    assert_eq!(server.next_request().path, "/v2/transactions/params");
+    assert!(signed_txn.verify().unwrap());
+    let txn = signed_txn.to_transaction().unwrap();
+    // without a fee per byte the minimum fee is used
+    assert_eq!(txn.header.fee, MicroAlgos(2000));
+    assert_eq!(txn.header.genesis_id, "testnet-v1.0");
+    assert_eq!(txn.header.genesis_hash.to_vec(), base64_decode("SGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiI=").unwrap());
+    assert_eq!(txn.header.first_valid, 20000);
+    assert_eq!(txn.header.last_valid, 21000);
+    assert_eq!(txn.payment_params.as_ref().unwrap().amount, MicroAlgos(5000));
+
+    let signed_txn = client.payment(&sender, &receiver.address.to_string(), 5000).unwrap();
+    let txn = signed_txn.to_transaction().unwrap();
+    assert_eq!(txn.header.fee.0, txn.estimate_fee(20, 1000).unwrap());
+    assert!(txn.header.fee > MicroAlgos(2000));
+  }
+
+  #[test]
+  fn test_payment_fee_per_byte_is_raised_to_network_min_fee() {
+    let server = MockServer::start(vec![params_response(1)]);
+    let client = AlgodClient::new(&server.address, "token");
+    let sender = Account::generate();
+
+    let signed_txn = client.payment(&sender, &Account::generate().address.to_string(), 5000).unwrap();
+    let txn = signed_txn.to_transaction().unwrap();
+    // 1 micro algo per byte is below both minimum fees, the one of the network is higher
+    assert!(txn.estimate_fee(1, 0).unwrap() < 1000);
+    assert_eq!(txn.header.fee, MicroAlgos(2000));
+    assert!(signed_txn.verify().unwrap());
+  }
+
+  #[test]
+  fn test_asset_information() {
+    let server = MockServer::start(vec![MockResponse::json(ASSET_RESPONSE)]);
+    let client = AlgodClient::new(&server.address, "token");
+
+    let asset_info = client.asset_information(1234).unwrap();
+    let request = server.next_request();
+    assert_eq!(request.method, "GET");
+    assert_eq!(request.path, "/v2/assets/1234");
+    assert_eq!(request.header("X-Algo-API-Token"), Some("token"));
+
+    assert_eq!(asset_info.index, 1234);
+    let params = asset_info.params;
+    assert_eq!(params.creator.to_string(), "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU");
+    assert_eq!(params.total, 1000000);
+    assert_eq!(params.decimals, 2);
+    assert_eq!(params.unit_name.as_ref().unwrap(), "TST");
+    assert_eq!(params.name.as_ref().unwrap(), "Test Token");
+    assert_eq!(params.url.as_ref().unwrap(), "https://example.com/token");
+    assert!(!params.default_frozen);
+    assert_eq!(params.manager.as_ref(), Some(&params.creator));
+    assert_eq!(params.reserve.unwrap().to_string(), "BH55E5RMBD4GYWXGX5W5PJ5JAHPGM5OXKDQH5DC4O2MGI7NW4H6VOE4CP4");
+    assert!(params.freeze.is_none());
+    assert_eq!(params.clawback.unwrap().to_string(), "PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI");
+  }
+
+  fn status_response(last_round: u64) -> MockResponse {
+    MockResponse::json(&format!(
+      r#"{{"last-round": {}, "last-version": "v1", "time-since-last-round": 1000, "catchup-time": 0}}"#,
+      last_round,
+    ))
+  }
+
+  fn block_response(round: u64, txn_count: usize) -> MockResponse {
+    let txns = vec![r#"{"sig": "c2ln", "txn": {"type": "pay"}}"#; txn_count];
+    MockResponse::json(&format!(
+      r#"{{"block": {{"gen": "devnet-v33.0", "gh": "JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI=", "prev": "blk-prev", "rnd": {}, "ts": 1600000000, "txns": [{}]}}}}"#,
+      round,
+      txns.join(","),
+    ))
+  }
+
+  #[test]
+  fn test_follow_blocks() {
+    let server = MockServer::start(vec![
+      status_response(12466),
+      block_response(12466, 2),
+      // the wait can time out before the next round
+      status_response(12466),
+      status_response(12467),
+      block_response(12467, 0),
+    ]);
+    let client = AlgodClient::new(&server.address, "token");
+
+    let blocks: Vec<_> = client.follow_blocks(12466).take(2).map(|block| block.unwrap()).collect();
+    assert_eq!(server.next_request().path, "/v2/status/wait-for-block-after/12465");
+    assert_eq!(server.next_request().path, "/v2/blocks/12466?format=json");
+    assert_eq!(server.next_request().path, "/v2/status/wait-for-block-after/12466");
+    assert_eq!(server.next_request().path, "/v2/status/wait-for-block-after/12466");
+    assert_eq!(server.next_request().path, "/v2/blocks/12467?format=json");
+
+    assert_eq!(blocks[0].round, 12466);
+    assert_eq!(blocks[0].genesis_id, "devnet-v33.0");
+    assert_eq!(blocks[0].timestamp, 1600000000);
+    assert_eq!(blocks[0].transactions.len(), 2);
+    assert_eq!(blocks[1].round, 12467);
+    assert!(blocks[1].transactions.is_empty());
+  }
+
+  #[test]
+  fn test_follow_blocks_retries_transient_errors() {
+    let server = MockServer::start(vec![
+      MockResponse::with_status(503, "unavailable"),
+      status_response(5),
+      MockResponse::with_status(500, "internal error"),
+      status_response(5),
+      block_response(5, 0),
+      status_response(6),
+      MockResponse::with_status(404, "no block"),
+    ]);
+    let client = AlgodClient::new(&server.address, "token");
+    let mut follower = client.follow_blocks(5);
+
+    assert_eq!(follower.next().unwrap().unwrap().round, 5);
+    let actual_error = follower.next().unwrap().unwrap_err();
+    match actual_error {
+      AlgorandSdkError::ApiError(404, _) => {},
+      other => panic!("Unexpected error {:?}", other),
+    }
+  }
+
+  #[test]
+  fn test_retries_server_errors() {
+    let server = MockServer::start(vec![
+      MockResponse::with_status(503, "unavailable"),
+      MockResponse::with_status(503, "unavailable"),
+      MockResponse::with_status(503, "unavailable"),
+    ]);
+    let client = AlgodClientBuilder::new(&server.address, "token").max_retries(2).build().unwrap();
+
+    let actual_error = client.status().unwrap_err();
+    match actual_error {
+      AlgorandSdkError::ApiError(503, _) => {},
+      other => panic!("Unexpected error {:?}", other),
+    }
+    for _ in 0..3 {
+      assert_eq!(server.next_request().path, "/v2/status");
+    }
+
+    let server = MockServer::start(vec![
+      MockResponse::with_status(503, "unavailable"),
+      status_response(12466),
+    ]);
+    let client = AlgodClientBuilder::new(&server.address, "token").max_retries(2).build().unwrap();
+    assert_eq!(client.status().unwrap().last_round, 12466);
+  }
+
+  #[test]
+  fn test_sent_transactions_are_not_retried() {
+    let sender = Account::generate();
+    let txns = assign_group_id(vec![payment(&sender, 1000), payment(&sender, 2000)]).unwrap();
+    let signed_txns = sender.sign_group(&txns).unwrap();
+    let server = MockServer::start(vec![
+      MockResponse::with_status(503, "unavailable"),
+      status_response(12466),
+    ]);
+    let client = AlgodClientBuilder::new(&server.address, "token")
+      .max_retries(2)
+      .retry_backoff(Duration::from_millis(1))
+      .build()
+      .unwrap();
+
+    assert!(client.send_raw_transaction_group(&signed_txns).is_err());
+    assert_eq!(server.next_request().method, "POST");
+    // the status is served by the response a retry would have got
+    assert_eq!(client.status().unwrap().last_round, 12466);
+  }
+
+  #[test]
+  fn test_client_errors_are_not_retried() {
+    let server = MockServer::start(vec![
+      MockResponse::with_status(404, "not found"),
+      status_response(12466),
+    ]);
+    let client = AlgodClientBuilder::new(&server.address, "token").max_retries(2).build().unwrap();
+
+    assert!(client.asset_information(1).is_err());
+    // the status is served by the response a retry would have got
+    assert_eq!(client.status().unwrap().last_round, 12466);
+  }
+
+  #[test]
+  fn test_timeout() {
+    // the listener accepts connections but never responds
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = format!("http://{}", listener.local_addr().unwrap());
+    let client = AlgodClientBuilder::new(&address, "token")
+      .timeout(Duration::from_millis(200))
+      .build()
+      .unwrap();
+
+    let start = Instant::now();
+    assert!(client.status().is_err());
+    assert!(start.elapsed() < Duration::from_secs(5));
+  }
+
+  #[test]
+  fn test_send_raw_transaction_group() {
+    let sender = Account::generate();
+    let txns = assign_group_id(vec![payment(&sender, 1000), payment(&sender, 2000)]).unwrap();
+    let signed_txns = sender.sign_group(&txns).unwrap();
+    let response = format!(r#"{{"txId": "{}"}}"#, signed_txns[0].txn_id);
+    let server = MockServer::start(vec![MockResponse::json(&response)]);
+    let client = AlgodClient::new(&server.address, "token");
+
+    let txn_id = client.send_raw_transaction_group(&signed_txns).unwrap();
+    assert_eq!(txn_id, signed_txns[0].txn_id);
+
+    let request = server.next_request();
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/v2/transactions");
+    assert_eq!(request.header("Content-Type"), Some("application/x-binary"));
+    let mut expected_body = signed_txns[0].encode().unwrap();
+    expected_body.extend(signed_txns[1].encode().unwrap());
+    assert_eq!(request.body, expected_body);
+  }
+
+  #[test]
+  fn test_send_raw_transaction_group_fails_without_group_id() {
+    let sender = Account::generate();
+    let signed_txns = vec![signed_payment(&sender, 1000), signed_payment(&sender, 2000)];
+    // nothing is sent, so the server has no responses
+    let server = MockServer::start(vec![]);
+    let client = AlgodClient::new(&server.address, "token");
+
+    let actual_error = client.send_raw_transaction_group(&signed_txns).unwrap_err();
+    match actual_error {
+      AlgorandSdkError::MismatchedGroupId() => {},
+      other => panic!("Unexpected error {:?}", other),
+    }
+
+    match client.send_raw_transaction_group(&[]).unwrap_err() {
+      AlgorandSdkError::EmptyTransactionGroup() => {},
+      other => panic!("Unexpected error {:?}", other),
+    }
+    let oversized_group: Vec<SignedTransaction> = (0..17).map(|_| signed_payment(&sender, 1000)).collect();
+    match client.send_raw_transaction_group(&oversized_group).unwrap_err() {
+      AlgorandSdkError::TooManyTransactionsInGroup { max: 16, got: 17 } => {},
+      other => panic!("Unexpected error {:?}", other),
+    }
+  }
+
+  #[test]
+  fn test_account_information() {
+    let address = "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU";
+    let response = format!(r#"{{
+      "address": "{}",
+      "amount": 5000000,
+      "amount-without-pending-rewards": 5000000,
+      "assets": [
+        {{"amount": 42, "asset-id": 1234, "is-frozen": true}},
+        {{"amount": 0, "asset-id": 5678, "is-frozen": false}}
+      ],
+      "min-balance": 300000,
+      "pending-rewards": 0,
+      "round": 12466,
+      "status": "Offline"
+    }}"#, address);
+    let server = MockServer::start(vec![MockResponse::json(&response)]);
+    let client = AlgodClient::new(&server.address, "token");
+
+    let account_info = client.account_information(&Address::from_string(address).unwrap()).unwrap();
+    assert_eq!(server.next_request().path, format!("/v2/accounts/{}", address));
+
+    assert_eq!(account_info.address.to_string(), address);
+    assert_eq!(account_info.amount, MicroAlgos(5000000));
+    assert_eq!(account_info.min_balance, MicroAlgos(300000));
+    assert_eq!(account_info.assets.len(), 2);
+
+    let holding = account_info.asset_balance(1234).unwrap();
+    assert_eq!(holding.amount, 42);
+    assert!(holding.is_frozen);
+    assert_eq!(account_info.asset_balance(5678).unwrap().amount, 0);
+    assert!(account_info.asset_balance(1).is_none());
+  }
+
+  #[test]
+  fn test_asset_information_not_found() {
+    let server = MockServer::start(vec![MockResponse::with_status(404, r#"{"message":"asset does not exist"}"#)]);
+    let client = AlgodClient::new(&server.address, "token");
+
+    let actual_error = client.asset_information(1).unwrap_err();
+    match actual_error {
+      AlgorandSdkError::ApiError(404, _) => {},
+      other => panic!("Unexpected error {:?}", other),
+    }
+  }
+
+  #[test]
+  fn test_pending_transactions() {
+    #[derive(Serialize)]
+    struct PendingTransactionsResponse<'a> {
+      #[serde(rename = "top-transactions")]
+      top_transactions: &'a [SignedTransaction],
+      #[serde(rename = "total-transactions")]
+      total_transactions: u64,
+    }
+
+    let sender = Account::generate();
+    let signed_txns = vec![signed_payment(&sender, 1000), signed_payment(&sender, 2000)];
+    let response = rmp_encode(&PendingTransactionsResponse {
+      top_transactions: &signed_txns,
+      total_transactions: 5,
+    }).unwrap();
+    let server = MockServer::start(vec![
+      MockResponse::msgpack(response.clone()),
+      MockResponse::msgpack(response),
+    ]);
+    let client = AlgodClient::new(&server.address, "token");
+
+    let pending_txns = client.pending_transactions(2).unwrap();
+    assert_eq!(server.next_request().path, "/v2/transactions/pending?max=2&format=msgpack");
+    assert_eq!(pending_txns.total_transactions, 5);
+    assert_eq!(pending_txns.top_transactions.len(), 2);
+    for (pending_txn, signed_txn) in pending_txns.top_transactions.iter().zip(signed_txns.iter()) {
+      assert_eq!(pending_txn.txn_id, signed_txn.txn_id);
+      assert_eq!(pending_txn.encode().unwrap(), signed_txn.encode().unwrap());
+    }
+
+    client.pending_transactions_by_address(&sender.address, 0).unwrap();
+    assert_eq!(
+      server.next_request().path,
+      format!("/v2/accounts/{}/transactions/pending?max=0&format=msgpack", sender.address.to_string()),
+    );
+  }
+
+  #[test]
+  fn test_application_information() {
+    let response = r#"{
+      "id": 1234,
+      "params": {
+        "approval-program": "AiABASI=",
+        "clear-state-program": "AiABASI=",
+        "creator": "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU",
+        "global-state": [
+          {"key": "b3duZXI=", "value": {"bytes": "dm90ZQ==", "type": 1, "uint": 0}},
+          {"key": "Y291bnQ=", "value": {"bytes": "", "type": 2, "uint": 42}}
+        ],
+        "global-state-schema": {"num-byte-slice": 1, "num-uint": 1},
+        "local-state-schema": {"num-byte-slice": 0, "num-uint": 2}
+      }
+    }"#;
+    let server = MockServer::start(vec![MockResponse::json(response)]);
+    let client = AlgodClient::new(&server.address, "token");
+
+    let app_info = client.application_information(1234).unwrap();
+    assert_eq!(server.next_request().path, "/v2/applications/1234");
+
+    assert_eq!(app_info.id, 1234);
+    let params = app_info.params;
+    assert_eq!(params.creator.to_string(), "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU");
+    assert_eq!(params.approval_program, vec![2, 32, 1, 1, 34]);
+    assert_eq!(params.clear_state_program, vec![2, 32, 1, 1, 34]);
+    assert_eq!(params.global_state_schema.num_byte_slice, 1);
+    assert_eq!(params.global_state_schema.num_uint, 1);
+    assert_eq!(params.local_state_schema.num_byte_slice, 0);
+    assert_eq!(params.local_state_schema.num_uint, 2);
+
+    assert_eq!(params.global_value(b"owner"), Some(&TealValue::Bytes(b"vote".to_vec())));
+    assert_eq!(params.global_value(b"count"), Some(&TealValue::Uint(42)));
+    assert!(params.global_value(b"missing").is_none());
+  }
+}
\ No newline at end of file
@@ -0,0 +1,245 @@
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use reqwest::blocking::Client;
+use super::{deserialize_address, send_with_retries, ClientConfig, INDEXER_API_TOKEN_HEADER};
+use crate::accounts::Address;
+use crate::errors::Error;
+use crate::transaction::{MicroAlgos, TxType};
+
+/// Client for the v2 REST api of an indexer, which searches the history of the network.
+///
+/// # Example
+/// ```rust,no_run
+/// use rust_algorand_sdk::accounts::Address;
+/// use rust_algorand_sdk::client::{IndexerClient, TransactionSearch};
+///
+/// let client = IndexerClient::new("http://localhost:8980", "indexer-api-token");
+/// let search = TransactionSearch {
+///   address: Some(Address::from_string("47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU").unwrap()),
+///   ..Default::default()
+/// };
+/// let txns = client.search_transactions_all(&search, 1000).unwrap();
+/// println!("Found {} transactions", txns.len());
+/// ```
+pub struct IndexerClient {
+  address: String,
+  token: String,
+  http_client: Client,
+  config: ClientConfig,
+}
+
+impl IndexerClient {
+  /// Creates a client for the indexer at `address`, authenticated with the indexer api `token`.
+  ///
+  /// Failed requests are not retried, use `with_config` to configure retries and timeouts
+  pub fn new(address: &str, token: &str) -> IndexerClient {
+    IndexerClient {
+      address: address.trim_end_matches('/').into(),
+      token: token.into(),
+      http_client: Client::new(),
+      config: ClientConfig::default(),
+    }
+  }
+
+  /// Creates a client for the indexer at `address` with the timeout and retry policy of `config`
+  pub fn with_config(address: &str, token: &str, config: ClientConfig) -> Result<IndexerClient, Error> {
+    Ok(IndexerClient {
+      address: address.trim_end_matches('/').into(),
+      token: token.into(),
+      http_client: config.http_client()?,
+      config,
+    })
+  }
+
+  /// Gets a page of the transactions matching `search`.
+  ///
+  /// Pass the `next_token` of the previous page to get the following page
+  pub fn search_transactions(&self, search: &TransactionSearch, next_token: Option<&str>) -> Result<TransactionsPage, Error> {
+    let mut query = search.to_query();
+    if let Some(next_token) = next_token {
+      query.push(("next", next_token.to_string()));
+    }
+    self.get_json("/v2/transactions", &query)
+  }
+
+  /// Gets at most `max` transactions matching `search`, following the pages until the last one
+  pub fn search_transactions_all(&self, search: &TransactionSearch, max: usize) -> Result<Vec<IndexerTransaction>, Error> {
+    let mut transactions: Vec<IndexerTransaction> = Vec::new();
+    let mut next_token: Option<String> = None;
+    loop {
+      let page = self.search_transactions(search, next_token.as_deref())?;
+      let is_empty = page.transactions.is_empty();
+      transactions.extend(page.transactions);
+
+      // an empty page ends the search even if it has a token, so a bad token can't loop forever
+      next_token = page.next_token;
+      if next_token.is_none() || is_empty || transactions.len() >= max {
+        break;
+      }
+    }
+
+    transactions.truncate(max);
+    Ok(transactions)
+  }
+
+  fn get_json<T: DeserializeOwned>(&self, path: &str, query: &[(&str, String)]) -> Result<T, Error> {
+    let request = self.http_client.get(format!("{}{}", self.address, path)).query(query);
+    Ok(send_with_retries(request, INDEXER_API_TOKEN_HEADER, &self.token, &self.config)?.json()?)
+  }
+}
+
+/// Filters of a transaction search. Unset filters match every transaction
+#[derive(Clone, Debug, Default)]
+pub struct TransactionSearch {
+  /// Only transactions sent or received by this address
+  pub address: Option<Address>,
+  pub tx_type: Option<TxType>,
+  pub min_round: Option<u64>,
+  pub max_round: Option<u64>,
+  /// Maximum number of transactions in each page
+  pub limit: Option<u64>,
+}
+
+impl TransactionSearch {
+  fn to_query(&self) -> Vec<(&'static str, String)> {
+    let mut query = Vec::new();
+    if let Some(ref address) = self.address {
+      query.push(("address", address.to_string()));
+    }
+    if let Some(tx_type) = self.tx_type {
+      query.push(("tx-type", tx_type.to_string()));
+    }
+    if let Some(min_round) = self.min_round {
+      query.push(("min-round", min_round.to_string()));
+    }
+    if let Some(max_round) = self.max_round {
+      query.push(("max-round", max_round.to_string()));
+    }
+    if let Some(limit) = self.limit {
+      query.push(("limit", limit.to_string()));
+    }
+    query
+  }
+}
+
+/// A page of transactions returned by a search
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct TransactionsPage {
+  pub current_round: u64,
+  /// Token of the next page, None on the last page
+  pub next_token: Option<String>,
+  pub transactions: Vec<IndexerTransaction>,
+}
+
+/// A confirmed transaction as returned by indexer
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct IndexerTransaction {
+  pub id: String,
+  #[serde(deserialize_with = "deserialize_address")]
+  pub sender: Address,
+  pub tx_type: String,
+  pub fee: MicroAlgos,
+  pub first_valid: u64,
+  pub last_valid: u64,
+  pub confirmed_round: Option<u64>,
+  pub round_time: Option<u64>,
+  /// Base64 encoded note
+  pub note: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::Duration;
+  use super::{IndexerClient, TransactionSearch};
+  use crate::client::ClientConfig;
+  use crate::accounts::Address;
+  use crate::client::mock::{MockResponse, MockServer};
+  use crate::transaction::{MicroAlgos, TxType};
+
+  fn page(ids: &[&str], next_token: Option<&str>) -> String {
+    let transactions: Vec<String> = ids.iter()
+      .map(|id| format!(r#"{{
+        "id": "{}",
+        "sender": "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU",
+        "tx-type": "pay",
+        "fee": 1000,
+        "first-valid": 12466,
+        "last-valid": 13466,
+        "confirmed-round": 12470,
+        "round-time": 1600000000
+      }}"#, id))
+      .collect();
+    let next_token = next_token.map(|token| format!(r#""next-token": "{}","#, token)).unwrap_or_default();
+    format!(r#"{{"current-round": 13000, {} "transactions": [{}]}}"#, next_token, transactions.join(","))
+  }
+
+  #[test]
+  fn test_search_transactions_follows_pages() {
+    let server = MockServer::start(vec![
+      MockResponse::json(&page(&["TXA", "TXB"], Some("page-2"))),
+      MockResponse::json(&page(&["TXC"], None)),
+    ]);
+    let client = IndexerClient::new(&server.address, "token");
+    let address = Address::from_string("47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU").unwrap();
+    let search = TransactionSearch {
+      address: Some(address.clone()),
+      tx_type: Some(TxType::Payment),
+      limit: Some(2),
+      ..Default::default()
+    };
+
+    let txns = client.search_transactions_all(&search, 10).unwrap();
+    let first_request = server.next_request();
+    assert_eq!(first_request.path, format!("/v2/transactions?address={}&tx-type=pay&limit=2", address.to_string()));
+    assert_eq!(first_request.header("X-Indexer-API-Token"), Some("token"));
+    assert_eq!(server.next_request().path, format!("/v2/transactions?address={}&tx-type=pay&limit=2&next=page-2", address.to_string()));
+
+    let ids: Vec<&str> = txns.iter().map(|txn| txn.id.as_str()).collect();
+    assert_eq!(ids, vec!["TXA", "TXB", "TXC"]);
+    assert_eq!(txns[0].sender, address);
+    assert_eq!(txns[0].fee, MicroAlgos(1000));
+    assert_eq!(txns[0].confirmed_round, Some(12470));
+    assert!(txns[0].note.is_none());
+  }
+
+  #[test]
+  fn test_with_config_retries_unavailable_and_rate_limited() {
+    let server = MockServer::start(vec![
+      MockResponse::with_status(503, "unavailable"),
+      MockResponse::with_status(429, "too many requests"),
+      MockResponse::json(&page(&["TXA"], None)),
+    ]);
+    let config = ClientConfig {
+      max_retries: 2,
+      retry_backoff: Duration::from_millis(1),
+      ..Default::default()
+    };
+    let client = IndexerClient::with_config(&server.address, "token", config).unwrap();
+
+    let page = client.search_transactions(&TransactionSearch::default(), None).unwrap();
+    assert_eq!(page.transactions[0].id, "TXA");
+    for _ in 0..3 {
+      assert_eq!(server.next_request().path, "/v2/transactions");
+    }
+  }
+
+  #[test]
+  fn test_search_transactions_stops_at_max() {
+    let server = MockServer::start(vec![MockResponse::json(&page(&["TXA", "TXB"], Some("page-2")))]);
+    let client = IndexerClient::new(&server.address, "token");
+
+    let first_page = client.search_transactions(&TransactionSearch::default(), None).unwrap();
+    assert_eq!(server.next_request().path, "/v2/transactions");
+    assert_eq!(first_page.next_token.as_ref().unwrap(), "page-2");
+    assert_eq!(first_page.current_round, 13000);
+
+    // the next page is not requested once max transactions are found
+    let server = MockServer::start(vec![MockResponse::json(&page(&["TXA", "TXB"], Some("page-2")))]);
+    let client = IndexerClient::new(&server.address, "token");
+    let txns = client.search_transactions_all(&TransactionSearch::default(), 1).unwrap();
+    assert_eq!(txns.len(), 1);
+    assert_eq!(txns[0].id, "TXA");
+  }
+}
\ No newline at end of file
@@ -0,0 +1,122 @@
+// A minimal http server for testing the clients against canned responses
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc::{channel, Receiver};
+use std::thread;
+
+pub struct MockResponse {
+  pub status: u16,
+  pub content_type: &'static str,
+  pub body: Vec<u8>,
+}
+
+impl MockResponse {
+  pub fn json(body: &str) -> MockResponse {
+    MockResponse::with_status(200, body)
+  }
+
+  pub fn msgpack(body: Vec<u8>) -> MockResponse {
+    MockResponse {
+      status: 200,
+      content_type: "application/msgpack",
+      body,
+    }
+  }
+
+  pub fn with_status(status: u16, body: &str) -> MockResponse {
+    MockResponse {
+      status,
+      content_type: "application/json",
+      body: body.as_bytes().to_vec(),
+    }
+  }
+}
+
+/// A request received by the mock server
+pub struct MockRequest {
+  pub method: String,
+  pub path: String,
+  pub headers: Vec<(String, String)>,
+  pub body: Vec<u8>,
+}
+
+impl MockRequest {
+  pub fn header(&self, name: &str) -> Option<&str> {
+    self.headers.iter()
+      .find(|(header, _)| header.eq_ignore_ascii_case(name))
+      .map(|(_, value)| value.as_str())
+  }
+}
+
+pub struct MockServer {
+  pub address: String,
+  requests: Receiver<MockRequest>,
+}
+
+impl MockServer {
+  /// Serves the responses in order, one per connection
+  pub fn start(responses: Vec<MockResponse>) -> MockServer {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = format!("http://{}", listener.local_addr().unwrap());
+    let (sender, requests) = channel();
+
+    thread::spawn(move || {
+      for response in responses {
+        let (mut stream, _) = match listener.accept() {
+          Ok(connection) => connection,
+          Err(_) => return,
+        };
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).unwrap();
+        let mut request_parts = request_line.split_whitespace();
+        let method = request_parts.next().unwrap_or_default().to_string();
+        let path = request_parts.next().unwrap_or_default().to_string();
+
+        let mut headers = Vec::new();
+        loop {
+          let mut line = String::new();
+          reader.read_line(&mut line).unwrap();
+          let line = line.trim_end();
+          if line.is_empty() {
+            break;
+          }
+          if let Some(index) = line.find(':') {
+            headers.push((line[..index].trim().to_string(), line[index + 1..].trim().to_string()));
+          }
+        }
+
+        let request = MockRequest { method, path, headers, body: Vec::new() };
+        let content_length = request.header("content-length")
+          .map(|length| length.parse::<usize>().unwrap())
+          .unwrap_or(0);
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+
+        write!(
+          stream,
+          "HTTP/1.1 {} Mock\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+          response.status,
+          response.content_type,
+          response.body.len(),
+        ).unwrap();
+        stream.write_all(&response.body).unwrap();
+        stream.flush().unwrap();
+
+        if sender.send(MockRequest { body, ..request }).is_err() {
+          return;
+        }
+      }
+    });
+
+    MockServer { address, requests }
+  }
+
+  /// Returns the next request the server received
+  pub fn next_request(&self) -> MockRequest {
+    self.requests.recv().unwrap()
+  }
+}
\ No newline at end of file
@@ -1,2 +1,134 @@
 pub mod algod;
-pub mod kmd;
\ No newline at end of file
+pub mod indexer;
+pub mod kmd;
+
+#[cfg(test)]
+mod mock;
+
+use std::cmp::min;
+use std::thread::sleep;
+use std::time::Duration;
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use serde::{de, Deserialize, Deserializer};
+use crate::accounts::Address;
+use crate::encoding::decode_base64;
+use crate::errors::{AlgorandSdkError, Error};
+
+pub use algod::{AlgodClient, AlgodClientBuilder, AccountInfo, ApplicationInfo, ApplicationParams, AssetHolding, AssetInfo, AssetInfoParams, Block, BlockFollower, NodeStatus, PendingTransactions, StateSchema, TealKeyValue, TealValue};
+pub use indexer::{IndexerClient, IndexerTransaction, TransactionSearch, TransactionsPage};
+
+/// Header used to authenticate requests to algod
+pub const ALGOD_API_TOKEN_HEADER: &str = "X-Algo-API-Token";
+
+/// Header used to authenticate requests to indexer
+pub const INDEXER_API_TOKEN_HEADER: &str = "X-Indexer-API-Token";
+
+/// Default timeout of a request, which is the timeout of clients created with `new`
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default delay before the first retry of a failed request. It doubles after each retry up to `RETRY_MAX_DELAY`
+pub const RETRY_INITIAL_DELAY: Duration = Duration::from_millis(100);
+pub const RETRY_MAX_DELAY: Duration = Duration::from_secs(10);
+
+/// Timeout and retry policy of the clients.
+///
+/// Only GET requests are retried. Transactions sent to the network are not,
+/// as the node may have accepted them before failing
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use rust_algorand_sdk::client::{ClientConfig, IndexerClient};
+///
+/// let config = ClientConfig {
+///   timeout: Duration::from_secs(10),
+///   max_retries: 3,
+///   ..Default::default()
+/// };
+/// let client = IndexerClient::with_config("http://localhost:8980", "indexer-api-token", config).unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct ClientConfig {
+  /// Maximum duration of each request, including reading the response
+  pub timeout: Duration,
+  /// Number of times a request is sent again after a network error, a 429 or a 5xx response
+  pub max_retries: u32,
+  /// Delay before the first retry. It doubles after each retry up to `RETRY_MAX_DELAY`
+  pub retry_backoff: Duration,
+}
+
+impl Default for ClientConfig {
+  fn default() -> ClientConfig {
+    ClientConfig {
+      timeout: DEFAULT_TIMEOUT,
+      max_retries: 0,
+      retry_backoff: RETRY_INITIAL_DELAY,
+    }
+  }
+}
+
+impl ClientConfig {
+  fn http_client(&self) -> Result<Client, Error> {
+    Ok(Client::builder().timeout(self.timeout).build()?)
+  }
+}
+
+// Sends the request like `send_authenticated`, retrying transient errors as configured by `config`
+fn send_with_retries(request: RequestBuilder, token_header: &str, token: &str, config: &ClientConfig) -> Result<Response, Error> {
+  let mut retry_delay = config.retry_backoff;
+  let mut retries = 0;
+  loop {
+    // requests with a streamed body can't be sent again
+    let attempt = match request.try_clone() {
+      Some(attempt) if retries < config.max_retries => attempt,
+      _ => return send_authenticated(request, token_header, token),
+    };
+    match send_authenticated(attempt, token_header, token) {
+      Err(ref error) if is_transient(error) => {
+        sleep(retry_delay);
+        retry_delay = min(retry_delay * 2, RETRY_MAX_DELAY);
+        retries += 1;
+      },
+      result => return result,
+    }
+  }
+}
+
+// Network errors, rate limited requests and server errors can succeed when retried
+fn is_transient(error: &Error) -> bool {
+  match error {
+    AlgorandSdkError::ApiError(status, _) => *status == 429 || *status >= 500,
+    AlgorandSdkError::Http(error) => error.is_connect() || error.is_timeout(),
+    _ => false,
+  }
+}
+
+// Sends the request authenticated with `token`, returning an Error for unsuccessful responses
+fn send_authenticated(request: RequestBuilder, token_header: &str, token: &str) -> Result<Response, Error> {
+  let response = request.header(token_header, token).send()?;
+  let status = response.status();
+  if status.is_success() {
+    Ok(response)
+  } else {
+    Err(AlgorandSdkError::ApiError(status.as_u16(), response.text().unwrap_or_default()))?
+  }
+}
+
+// Deserializes a checksumed address string returned by the apis
+fn deserialize_address<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Address, D::Error> {
+  let address = String::deserialize(deserializer)?;
+  Address::from_string(&address).map_err(de::Error::custom)
+}
+
+fn deserialize_optional_address<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Address>, D::Error> {
+  match Option::<String>::deserialize(deserializer)? {
+    Some(address) => Address::from_string(&address).map(Some).map_err(de::Error::custom),
+    None => Ok(None),
+  }
+}
+
+// Deserializes a base64 string returned by the apis, such as a program or a state key
+fn deserialize_base64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+  let data = String::deserialize(deserializer)?;
+  decode_base64(&data).map_err(de::Error::custom)
+}
\ No newline at end of file
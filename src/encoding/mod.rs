@@ -1,33 +1,125 @@
-use serde::{de::DeserializeOwned, Serialize};
-use rmp_serde::{encode, decode, from_read};
-use base32::{Alphabet, encode as base32_enc, decode as base32_dec};
+//! Encoding helpers for msgpack, base32 and base64 data used by algorand
+
+#[cfg(feature = "std")]
+mod msgpack;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::format;
 pub use base64::{decode as base64_decode, encode as base64_encode};
+use crate::errors::{AlgorandSdkError, Error};
 
-/// rmp encodes the serialized data.
-/// NOTE: Any struct to be serialized should have its fields sorted
-/// as per required by algorand blockchain
-pub fn rmp_encode<Data: Serialize>(data: &Data) -> Result<Vec<u8>, encode::Error> {
-  encode::to_vec_named(data)
-}
+#[cfg(feature = "std")]
+pub use msgpack::{rmp_encode, rmp_decode, rmp_decode_all, rmp_decode_json};
 
-/// rmp decodes the byte array reference into type result.
-/// Type must implement DeserializedOwned
-pub fn rmp_decode<Data: DeserializeOwned>(buffer: &[u8]) -> Result<Data, decode::Error> {
-  from_read(buffer)
-}
+/// RFC 4648 base32 alphabet of addresses and transaction ids
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
 
+/// Encodes the data with the RFC 4648 base32 alphabet, without padding
 pub fn base32_encode(data: &[u8]) -> String {
-  base32_enc(Alphabet::RFC4648 { padding: false }, data)
+  let mut encoded = String::with_capacity((data.len() * 8).div_ceil(5));
+  let mut buffer: u32 = 0;
+  let mut number_of_bits = 0;
+  for byte in data {
+    buffer = (buffer << 8 | u32::from(*byte)) & 0xfff;
+    number_of_bits += 8;
+    while number_of_bits >= 5 {
+      number_of_bits -= 5;
+      encoded.push(BASE32_ALPHABET[(buffer >> number_of_bits & 0x1f) as usize] as char);
+    }
+  }
+
+  // the remaining bits are padded with zeros to a full character
+  if number_of_bits > 0 {
+    encoded.push(BASE32_ALPHABET[(buffer << (5 - number_of_bits) & 0x1f) as usize] as char);
+  }
+  encoded
 }
 
+/// Decodes RFC 4648 base32 data, ignoring any trailing padding and the case of the letters.
+/// Returns None if the data has characters outside of the alphabet, a length no encoding
+/// produces, or non zero bits left over in the last character
 pub fn base32_decode(data: &str) -> Option<Vec<u8>> {
-  base32_dec(Alphabet::RFC4648 { padding: false }, data)
+  let data = data.trim_end_matches('=');
+  if let 1 | 3 | 6 = data.len() % 8 {
+    return None;
+  }
+  let mut decoded = Vec::with_capacity(data.len() * 5 / 8);
+  let mut buffer: u32 = 0;
+  let mut number_of_bits = 0;
+  for character in data.bytes() {
+    let value = BASE32_ALPHABET.iter().position(|&letter| letter == character.to_ascii_uppercase())?;
+    buffer = (buffer << 5 | value as u32) & 0xfff;
+    number_of_bits += 5;
+    if number_of_bits >= 8 {
+      number_of_bits -= 8;
+      decoded.push((buffer >> number_of_bits) as u8);
+    }
+  }
+
+  // the encoder pads the last character with zeros, anything else is not canonical
+  if buffer & ((1 << number_of_bits) - 1) != 0 {
+    return None;
+  }
+  Some(decoded)
+}
+
+/// base32 decodes the data, returning an `AlgorandSdkError::InvalidEncoding`
+/// if it is not valid unpadded base32
+pub fn decode_base32(data: &str) -> Result<Vec<u8>, Error> {
+  match base32_decode(data) {
+    Some(bytes) => Ok(bytes),
+    None => Err(AlgorandSdkError::InvalidEncoding("base32".into(), format!("could not decode {}", data)))?,
+  }
+}
+
+/// base64 decodes the data, returning an `AlgorandSdkError::InvalidEncoding`
+/// if it is not valid base64
+pub fn decode_base64(data: &str) -> Result<Vec<u8>, Error> {
+  match base64_decode(data) {
+    Ok(bytes) => Ok(bytes),
+    Err(err) => Err(AlgorandSdkError::InvalidEncoding("base64".into(), format!("could not decode {}: {}", data, err)))?,
+  }
+}
+
+/// Encodes the data with the unpadded URL safe base64 alphabet, so it can be used in URLs and query strings
+pub fn base64_url_encode(data: &[u8]) -> String {
+  base64::encode_config(data, base64::URL_SAFE_NO_PAD)
+}
+
+/// Decodes unpadded URL safe base64 data, returning an error if the data is not valid
+pub fn base64_url_decode(data: &str) -> Result<Vec<u8>, Error> {
+  match base64::decode_config(data, base64::URL_SAFE_NO_PAD) {
+    Ok(bytes) => Ok(bytes),
+    Err(err) => Err(AlgorandSdkError::InvalidEncoding("url safe base64".into(), format!("could not decode {}: {}", data, err)))?,
+  }
+}
+
+/// Encodes the data as lowercase hex
+pub fn hex_encode(data: &[u8]) -> String {
+  data.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Decodes lowercase or uppercase hex, returning an `AlgorandSdkError::InvalidEncoding`
+/// if it has an odd length or non hex characters
+pub fn decode_hex(data: &str) -> Result<Vec<u8>, Error> {
+  if data.len() % 2 != 0 {
+    Err(AlgorandSdkError::InvalidEncoding("hex".into(), format!("odd length {}", data.len())))?;
+  }
+
+  data.as_bytes().chunks(2)
+    .map(|pair| match (char::from(pair[0]).to_digit(16), char::from(pair[1]).to_digit(16)) {
+      (Some(high), Some(low)) => Ok((high << 4 | low) as u8),
+      _ => Err(AlgorandSdkError::InvalidEncoding("hex".into(), format!("could not decode {}", data)))?,
+    })
+    .collect()
 }
 
 #[cfg(test)]
 mod tests {
   use serde::{Deserialize, Serialize};
-  use super::{rmp_encode, rmp_decode};
+  use super::{rmp_encode, rmp_decode, rmp_decode_all, rmp_decode_json, base32_encode, decode_base32, decode_base64, base64_encode, base64_url_encode, base64_url_decode, hex_encode, decode_hex};
+  use crate::errors::AlgorandSdkError;
 
   #[test]
   fn encode_decode_works() {
@@ -120,4 +212,122 @@ mod tests {
 
     assert_eq!(actual_bytes, expected_bytes);
   }
+
+  #[test]
+  fn decode_base32_fails_for_invalid_input() {
+    let actual_error = decode_base32("not-base32!").unwrap_err();
+    if let AlgorandSdkError::InvalidEncoding(_, _) = actual_error {}
+    else {
+      panic!("Not invalid encoding")
+    }
+
+    assert_eq!(decode_base32("MFRGG").unwrap(), b"abc".to_vec());
+  }
+
+  #[test]
+  fn base32_encode_decode_rfc4648_vectors() {
+    let vectors = [("", ""), ("f", "MY"), ("fo", "MZXQ"), ("foo", "MZXW6"), ("foob", "MZXW6YQ"), ("fooba", "MZXW6YTB"), ("foobar", "MZXW6YTBOI")];
+    for (data, encoded) in vectors.iter() {
+      assert_eq!(base32_encode(data.as_bytes()), *encoded);
+      assert_eq!(decode_base32(encoded).unwrap(), data.as_bytes());
+    }
+
+    // padding and lowercase letters are accepted when decoding
+    assert_eq!(decode_base32("mzxw6ytboi======").unwrap(), b"foobar".to_vec());
+  }
+
+  #[test]
+  fn decode_base32_rejects_invalid_lengths() {
+    for encoded in ["M", "MZX", "MZXW6Y", "MZXW6YTBO"].iter() {
+      match decode_base32(encoded).unwrap_err() {
+        AlgorandSdkError::InvalidEncoding(_, _) => {},
+        other => panic!("Unexpected error {:?}", other),
+      }
+    }
+  }
+
+  #[test]
+  fn decode_base32_rejects_non_zero_trailing_bits() {
+    // "MY" is "f", "MZ" sets one of the two padding bits of the last character
+    for encoded in ["MZ", "MZXR", "MZXW7", "MZXW6YR", "MZXW6YTBOJ"].iter() {
+      match decode_base32(encoded).unwrap_err() {
+        AlgorandSdkError::InvalidEncoding(_, _) => {},
+        other => panic!("Unexpected error {:?}", other),
+      }
+    }
+  }
+
+  #[test]
+  fn decode_base64_fails_for_invalid_input() {
+    let actual_error = decode_base64("not base64!").unwrap_err();
+    if let AlgorandSdkError::InvalidEncoding(_, _) = actual_error {}
+    else {
+      panic!("Not invalid encoding")
+    }
+
+    assert_eq!(decode_base64("YWJj").unwrap(), b"abc".to_vec());
+  }
+
+  #[test]
+  fn base64_url_encode_decode_works() {
+    let data: Vec<u8> = (0..=255).collect();
+    let encoded = base64_url_encode(&data);
+    assert_eq!(base64_url_decode(&encoded).unwrap(), data);
+
+    assert!(base64_url_decode("not base64!").is_err());
+  }
+
+  #[test]
+  fn base64_url_encode_differs_from_standard() {
+    // these bytes encode to + and / with the standard alphabet
+    let data = [0xfb, 0xff, 0xbf];
+    assert_eq!(base64_encode(data), "+/+/");
+    assert_eq!(base64_url_encode(&data), "-_-_");
+  }
+
+  #[test]
+  fn rmp_decode_json_converts_binary_to_base64() {
+    #[derive(Deserialize, Serialize)]
+    struct Obj {
+      #[serde(with = "serde_bytes", rename = "J")]
+      j: Vec<u8>,
+      #[serde(rename = "K")]
+      k: String,
+      #[serde(rename = "L")]
+      l: Vec<u16>,
+    }
+
+    let bytes = rmp_encode(&Obj {
+      j: b"abc".to_vec(),
+      k: "aaa".into(),
+      l: vec![1, 300],
+    }).unwrap();
+
+    let json_value = rmp_decode_json(&bytes).unwrap();
+    assert_eq!(json_value.to_string(), r#"{"J":"YWJj","K":"aaa","L":[1,300]}"#);
+  }
+
+  #[test]
+  fn rmp_decode_all_decodes_concatenated_values() {
+    let mut bytes = rmp_encode(&"first").unwrap();
+    bytes.extend(rmp_encode(&"second").unwrap());
+
+    let values: Vec<String> = rmp_decode_all(&bytes).unwrap();
+    assert_eq!(values, vec!["first".to_string(), "second".to_string()]);
+  }
+
+  #[test]
+  fn hex_round_trips() {
+    let bytes = vec![0x00, 0x0f, 0xa5, 0xff];
+    assert_eq!(hex_encode(&bytes), "000fa5ff");
+    assert_eq!(decode_hex("000fa5ff").unwrap(), bytes);
+    assert_eq!(decode_hex("000FA5FF").unwrap(), bytes);
+
+    for invalid in &["abc", "zz", "+f", "é0"] {
+      match decode_hex(invalid).unwrap_err() {
+        AlgorandSdkError::InvalidEncoding(_, _) => {},
+        other => panic!("Unexpected error {:?}", other),
+      }
+    }
+  }
 }
\ No newline at end of file
@@ -0,0 +1,114 @@
+//! Msgpack encoding of the data sent to algorand and its json representation
+
+use std::fmt;
+use serde::{de, de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+use rmp_serde::{encode, decode, from_read};
+use super::base64_encode;
+use crate::errors::Error;
+
+/// rmp encodes the serialized data.
+/// NOTE: Any struct to be serialized should have its fields sorted
+/// as per required by algorand blockchain
+pub fn rmp_encode<Data: Serialize>(data: &Data) -> Result<Vec<u8>, encode::Error> {
+  encode::to_vec_named(data)
+}
+
+/// rmp decodes the byte array reference into type result.
+/// Type must implement DeserializedOwned
+pub fn rmp_decode<Data: DeserializeOwned>(buffer: &[u8]) -> Result<Data, decode::Error> {
+  from_read(buffer)
+}
+
+/// rmp decodes a byte array of concatenated rmp encoded values.
+/// Values are decoded one after the other until the buffer is consumed
+pub fn rmp_decode_all<Data: DeserializeOwned>(buffer: &[u8]) -> Result<Vec<Data>, decode::Error> {
+  let mut remaining = buffer;
+  let mut values = Vec::new();
+  while !remaining.is_empty() {
+    values.push(from_read(&mut remaining)?);
+  }
+  Ok(values)
+}
+
+/// Decodes rmp encoded data into a json value for inspection.
+/// Binary data is converted to base64 strings.
+pub fn rmp_decode_json(buffer: &[u8]) -> Result<Value, Error> {
+  let json_value: JsonValue = rmp_decode(buffer)?;
+  Ok(json_value.0)
+}
+
+/// This is for internal use only. Wraps a json value that can be deserialized from any format
+struct JsonValue(Value);
+
+struct JsonValueVisitor;
+
+impl<'de> de::Visitor<'de> for JsonValueVisitor {
+  type Value = JsonValue;
+
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    formatter.write_str("any value that can be represented as json")
+  }
+
+  fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+    Ok(JsonValue(Value::from(v)))
+  }
+
+  fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+    Ok(JsonValue(Value::from(v)))
+  }
+
+  fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+    Ok(JsonValue(Value::from(v)))
+  }
+
+  fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+    Ok(JsonValue(Value::from(v)))
+  }
+
+  fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+    Ok(JsonValue(Value::from(v)))
+  }
+
+  fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+    Ok(JsonValue(Value::from(base64_encode(v))))
+  }
+
+  fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+    Ok(JsonValue(Value::Null))
+  }
+
+  fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+    Ok(JsonValue(Value::Null))
+  }
+
+  fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+    JsonValue::deserialize(deserializer)
+  }
+
+  fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+    let mut values: Vec<Value> = Vec::new();
+    while let Some(JsonValue(value)) = seq.next_element()? {
+      values.push(value);
+    }
+    Ok(JsonValue(Value::Array(values)))
+  }
+
+  fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+    let mut values: Map<String, Value> = Map::new();
+    while let Some((JsonValue(key), JsonValue(value))) = map.next_entry()? {
+      let key = match key {
+        Value::String(key) => key,
+        other => other.to_string(),
+      };
+      values.insert(key, value);
+    }
+    Ok(JsonValue(Value::Object(values)))
+  }
+}
+
+impl<'de> Deserialize<'de> for JsonValue {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<JsonValue, D::Error> {
+    deserializer.deserialize_any(JsonValueVisitor)
+  }
+}
\ No newline at end of file
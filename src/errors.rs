@@ -1,25 +1,118 @@
-use failure::Fail;
-pub use failure::Error;
+use alloc::string::String;
 
-#[derive(Debug, Fail)]
+/// The error of every fallible function, so callers can match on it directly
+pub type Error = AlgorandSdkError;
+
+#[derive(Debug, thiserror::Error)]
 pub enum AlgorandSdkError {
   // Mnemonic Errors
-  #[fail(display = "Invalid Mnemonic Phrase. Should have 25 words but got: {}", _0)]
-  InvalidPhrase(String),
-  #[fail(display = "Invalid word [{}] found in phrase", _0)]
+  #[error("Invalid Mnemonic Phrase. Should have {0} words but got {1}")]
+  InvalidPhrase(usize, usize),
+  #[error("Invalid word [{0}] found in phrase")]
   InvalidPhraseWord(String),
-  #[fail(display = "Invalid Checksum")]
+  #[error("Invalid Checksum")]
   InvalidChecksum(),
-  #[fail(display = "Invalid Seed for Mnemonic")]
+  #[error("Invalid Seed for Mnemonic")]
   InvalidSeed(),
-  #[fail(display = "Error with mnemonic: {}", _0)]
+  #[error("Error with mnemonic: {0}")]
   GenericError(String),
 
   // Account Errors
-  #[fail(display = "Invalid checksum address {}", _0)]
+  #[error("Invalid checksum address {0}")]
   InvalidChecksumAddress(String),
-  #[fail(display = "Wrong address length, should be {} length got {}", _0, _1)]
+  #[error("Wrong address length, should be {0} length got {1}")]
   WrongAddressLength(usize, usize),
-  #[fail(display = "Wrong address byte length, should be {} length got {}", _0, _1)]
+  #[error("Wrong address byte length, should be {0} length got {1}")]
   WrongAddressByteLength(usize, usize),
+  #[error("Wrong secret key byte length, should be {0} length got {1}")]
+  WrongSecretKeyByteLength(usize, usize),
+  #[error("Public key does not match the secret key")]
+  MismatchedPublicKey(),
+  #[error("Invalid multisig account: {0}")]
+  InvalidMultisigAccount(String),
+  #[error("OS entropy source is unavailable: {0}")]
+  EntropyUnavailable(String),
+
+  // Transaction Errors
+  #[error("Genesis hash required")]
+  MissingGenesisHash(),
+  #[error("Expected genesis hash to be {expected} bytes but got {got}")]
+  InvalidGenesisHashLength { expected: usize, got: usize },
+  #[error("Transaction note should be at most {max} bytes but got {got}")]
+  NoteTooLong { max: usize, got: usize },
+  #[error("Transaction fee overflowed")]
+  FeeOverflow(),
+  #[error("Fee per byte is 0. Set a fee or use a flat fee")]
+  ZeroFeePerByte(),
+  #[error("Transaction amount plus fee overflowed")]
+  AmountOverflow(),
+  #[error("close_remainder_to should not be the sender address {0}. Set allow_self_close to allow it")]
+  CloseToSender(String),
+  #[error("Invalid amount of algos {0}: {1}")]
+  InvalidAlgoAmount(String, String),
+  #[error("Could not decode signed transaction: {0}")]
+  InvalidSignedTransactionEncoding(String),
+  #[error("Expected a single signed transaction but got {0}")]
+  NotSingleSignedTransaction(usize),
+  #[error("Invalid signature for transaction {0}")]
+  InvalidTransactionSignature(String),
+  #[error("Transaction is not multisig signed")]
+  NotMultisigSigned(),
+  #[error("Account {0} is not a participant of the multisig account")]
+  NotMultisigParticipant(String),
+  #[error("Account {0} already signed the transaction")]
+  MultisigAlreadySigned(String),
+  #[error("Signer {0} is not the auth address {1}")]
+  MismatchedAuthAddress(String, String),
+  #[error("Invalid key registration: {0}")]
+  InvalidKeyReg(String),
+  #[error("Transaction validity window should be at most {max} rounds but got {got}")]
+  InvalidValidityWindow { max: u64, got: u64 },
+  #[error("Transaction last valid round overflowed")]
+  RoundOverflow(),
+  #[error("Transaction group should have at most {max} transactions but got {got}")]
+  TooManyTransactionsInGroup { max: usize, got: usize },
+  #[error("Transaction {index} of the group is for genesis {got} but the first transaction is for genesis {expected}")]
+  MismatchedGroupGenesis { index: usize, expected: String, got: String },
+  #[error("Transaction belongs to a transaction group but has no group id assigned. Sign the built group instead")]
+  MissingGroupId(),
+  #[error("Transaction group is empty")]
+  EmptyTransactionGroup(),
+  #[error("Transactions should all have the same group id assigned. Use assign_group_id to assign it")]
+  MismatchedGroupId(),
+  #[error("Transaction already has a group id assigned")]
+  GroupIdAlreadyAssigned(),
+  #[error("Expected {expected} signers for transaction group but got {got}")]
+  MismatchedGroupSigners { expected: usize, got: usize },
+  #[error("Atomic swap expected a {expected} transaction but got {got}")]
+  InvalidAtomicSwap { expected: String, got: String },
+  #[error("Unknown on completion {0}")]
+  UnknownOnComplete(u64),
+  #[error("Invalid asset param {0}: {1}")]
+  InvalidAssetParam(String, String),
+
+  // Encoding Errors
+  #[error("Invalid {0} encoding: {1}")]
+  InvalidEncoding(String, String),
+
+  // Client Errors
+  #[error("Request failed with status {0}: {1}")]
+  ApiError(u16, String),
+
+  // Errors of the encoding, crypto, io and http libraries, displayed as they are
+  #[cfg(feature = "std")]
+  #[error(transparent)]
+  MsgpackEncode(#[from] rmp_serde::encode::Error),
+  #[cfg(feature = "std")]
+  #[error(transparent)]
+  MsgpackDecode(#[from] rmp_serde::decode::Error),
+  #[cfg(feature = "std")]
+  #[error(transparent)]
+  Signature(#[from] ed25519_dalek::SignatureError),
+  #[cfg(feature = "std")]
+  #[error(transparent)]
+  Io(#[from] std::io::Error),
+  #[cfg(feature = "client")]
+  #[error(transparent)]
+  Http(#[from] reqwest::Error),
 }
\ No newline at end of file
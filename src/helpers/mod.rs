@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 // helper trait for moving from slices to fixed array
 pub trait ToArray<T> {
   fn to_array(&self) -> T;
@@ -2,11 +2,21 @@
 //! 
 //! You can use it for standalone purposes like account management and also interacting
 //! with an algorand library
+//! 
+//! Without the default `std` feature the crate is `no_std` and only needs `alloc`.
+//! It then provides mnemonics, addresses, minimum balances and signing with an [`accounts::Account`],
+//! while transactions and the clients need `std`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 mod helpers;
-mod encoding;
+pub mod encoding;
 
 pub mod accounts;
 pub mod errors;
+#[cfg(feature = "client")]
 pub mod client;
+#[cfg(feature = "std")]
 pub mod transaction;
\ No newline at end of file
@@ -0,0 +1,136 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::accounts::Address;
+use crate::errors::{AlgorandSdkError, Error};
+
+/// What an application call does after the approval program runs.
+///
+/// It is encoded as the integer algod expects, with `NoOp` being 0
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OnComplete {
+  /// Only runs the approval program
+  #[default]
+  NoOp,
+  /// Allocates local state for the sender
+  OptIn,
+  /// Removes the local state of the sender
+  CloseOut,
+  /// Removes the local state of the sender even if the clear state program rejects
+  ClearState,
+  /// Replaces the programs of the application
+  UpdateApplication,
+  /// Deletes the application
+  DeleteApplication,
+}
+
+impl OnComplete {
+  pub fn from_u64(on_complete: u64) -> Result<OnComplete, Error> {
+    match on_complete {
+      0 => Ok(OnComplete::NoOp),
+      1 => Ok(OnComplete::OptIn),
+      2 => Ok(OnComplete::CloseOut),
+      3 => Ok(OnComplete::ClearState),
+      4 => Ok(OnComplete::UpdateApplication),
+      5 => Ok(OnComplete::DeleteApplication),
+      others => Err(AlgorandSdkError::UnknownOnComplete(others))?,
+    }
+  }
+
+  pub fn to_u64(self) -> u64 {
+    match self {
+      OnComplete::NoOp => 0,
+      OnComplete::OptIn => 1,
+      OnComplete::CloseOut => 2,
+      OnComplete::ClearState => 3,
+      OnComplete::UpdateApplication => 4,
+      OnComplete::DeleteApplication => 5,
+    }
+  }
+}
+
+impl Serialize for OnComplete {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(self.to_u64())
+  }
+}
+
+impl<'de> Deserialize<'de> for OnComplete {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<OnComplete, D::Error> {
+    OnComplete::from_u64(u64::deserialize(deserializer)?)
+      .map_err(|err| serde::de::Error::custom(err.to_string()))
+  }
+}
+
+/// An application call argument, encoded the way the TEAL opcodes read it.
+///
+/// # Example
+/// ```rust
+/// use rust_algorand_sdk::transaction::AppArg;
+///
+/// assert_eq!(AppArg::from_u64(1).as_bytes(), &[0, 0, 0, 0, 0, 0, 0, 1]);
+/// assert_eq!(AppArg::from_string("vote").as_bytes(), b"vote");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AppArg(pub Vec<u8>);
+
+impl AppArg {
+  /// Encodes the integer as 8 big-endian bytes, as read by `btoi`
+  pub fn from_u64(value: u64) -> AppArg {
+    AppArg(value.to_be_bytes().to_vec())
+  }
+
+  /// Encodes the address as its 32 public key bytes
+  pub fn from_address(address: &Address) -> AppArg {
+    AppArg(address.to_vec())
+  }
+
+  /// Encodes the string as its UTF-8 bytes
+  pub fn from_string(value: &str) -> AppArg {
+    AppArg(value.as_bytes().to_vec())
+  }
+
+  pub fn as_bytes(&self) -> &[u8] {
+    &self.0
+  }
+}
+
+impl From<AppArg> for Vec<u8> {
+  fn from(app_arg: AppArg) -> Vec<u8> {
+    app_arg.0
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{AlgorandSdkError, AppArg, OnComplete};
+  use crate::accounts::Address;
+  use crate::encoding::{rmp_encode, rmp_decode};
+
+  #[test]
+  fn test_app_arg_from_u64() {
+    let arg = AppArg::from_u64(1);
+    assert_eq!(arg.as_bytes().len(), 8);
+    assert_eq!(arg.as_bytes()[7], 0x01);
+    assert_eq!(AppArg::from_u64(0x0102).as_bytes(), &[0, 0, 0, 0, 0, 0, 1, 2]);
+  }
+
+  #[test]
+  fn test_app_arg_from_address_and_string() {
+    let address = Address::from_string("47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU").unwrap();
+    assert_eq!(AppArg::from_address(&address).as_bytes(), address.as_bytes());
+    assert_eq!(AppArg::from_address(&address).as_bytes().len(), 32);
+
+    let bytes: Vec<u8> = AppArg::from_string("héllo").into();
+    assert_eq!(bytes, "héllo".as_bytes().to_vec());
+  }
+
+  #[test]
+  fn test_on_complete_encodes_as_integer() {
+    assert_eq!(rmp_encode(&OnComplete::DeleteApplication).unwrap(), rmp_encode(&5u64).unwrap());
+    let decoded: OnComplete = rmp_decode(&rmp_encode(&1u64).unwrap()).unwrap();
+    assert_eq!(decoded, OnComplete::OptIn);
+    match OnComplete::from_u64(6).unwrap_err() {
+      AlgorandSdkError::UnknownOnComplete(6) => {},
+      other => panic!("Unexpected error {:?}", other),
+    }
+  }
+}
\ No newline at end of file
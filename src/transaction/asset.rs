@@ -1,8 +1,16 @@
 use serde::{Deserialize, Serialize};
 use serde_bytes::ByteBuf;
+use crate::accounts::{Address, AddressBytes};
+use crate::errors::{AlgorandSdkError, Error};
+
+pub const MAX_UNIT_NAME_LENGTH: usize = 8;
+pub const MAX_ASSET_NAME_LENGTH: usize = 32;
+pub const MAX_ASSET_URL_LENGTH: usize = 96;
+pub const ASSET_METADATA_HASH_LENGTH: usize = 32;
+pub const MAX_ASSET_DECIMALS: u64 = 19;
 
 /// AssetID is a name of an asset
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct AssetID {
   #[serde(with = "serde_bytes", rename = "c", skip_serializing_if = "Vec::is_empty")]
   pub creator: Vec<u8>,
@@ -11,18 +19,36 @@ pub struct AssetID {
 }
 
 /// AssetParams describes the parameters of an asset
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
 pub struct AssetParams {
+  /// metadata_hash specifies a commitment to some unspecified asset
+  /// metadata, such as the hash of an ARC-3 metadata file. Should be 32 bytes long
+  #[serde(rename = "am", skip_serializing_if = "Option::is_none")]
+  pub metadata_hash: Option<ByteBuf>,
+
   /// asset_name specifies a hint for the name of a unit of this asset
   /// should be 32 bytes long
   #[serde(rename = "an", skip_serializing_if = "Option::is_none")]
   pub asset_name: Option<ByteBuf>,
 
+  /// url specifies a URL where more information about the asset can be
+  /// retrieved. Should be at most 96 bytes long
+  #[serde(rename = "au", skip_serializing_if = "Option::is_none")]
+  pub url: Option<ByteBuf>,
+
   /// clawback specifies an account that is allowed to take units
   /// of this asset from any account.
   #[serde(rename = "c", skip_serializing_if = "Option::is_none")]
   pub clawback: Option<ByteBuf>,
 
+  /// decimals specifies the number of digits to display after the decimal
+  /// place when displaying units of this asset. At most 19.
+  ///
+  /// Like every other zero value in the canonical encoding, 0 decimals is not
+  /// encoded, so `Some(0)` and `None` both create an asset with 0 decimals
+  #[serde(rename = "dc", default, skip_serializing_if = "is_none_or_zero")]
+  pub decimals: Option<u64>,
+
   /// default_frozen specifies whether slots for this asset 
   /// in user accounts are frozen by default or not.
   #[serde(rename = "df", skip_serializing_if = "Option::is_none")]
@@ -62,4 +88,285 @@ pub struct AssetConfigTransactionParams {
 
   /// These are params for the asset being created or re-configured.
   pub asset_params: Option<AssetParams>,
+}
+
+/// Captures the fields used for asset transfers, opt ins and revocations.
+#[derive(Clone, Debug)]
+pub struct AssetTransferTransactionParams {
+  /// asset_id is the asset being transferred
+  pub asset_id: u64,
+
+  /// amount of units of the asset being transferred
+  pub amount: u64,
+
+  /// When set, the units are revoked from this address instead of the sender.
+  /// Only the clawback account of the asset can send such transactions
+  pub revocation_target: Option<AddressBytes>,
+
+  pub receiver: AddressBytes,
+
+  /// When set, the sender holding of the asset is closed and the remaining
+  /// units are transferred to this address
+  pub close_assets_to: Option<AddressBytes>,
+}
+
+/// Builds `AssetParams`, validating the length of each field.
+/// 
+/// # Example
+/// ```rust
+/// use rust_algorand_sdk::transaction::AssetParamsBuilder;
+/// 
+/// let asset_params = AssetParamsBuilder::new()
+///   .total(1000)
+///   .unit_name("TOK")
+///   .asset_name("Token")
+///   .manager("47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU")
+///   .build()
+///   .unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AssetParamsBuilder {
+  metadata_hash: Option<Vec<u8>>,
+  asset_name: Option<String>,
+  url: Option<String>,
+  clawback: Option<String>,
+  decimals: Option<u64>,
+  default_frozen: Option<bool>,
+  freeze: Option<String>,
+  manager: Option<String>,
+  reserve: Option<String>,
+  total: Option<u64>,
+  unit_name: Option<String>,
+}
+
+impl AssetParamsBuilder {
+  pub fn new() -> AssetParamsBuilder {
+    Default::default()
+  }
+
+  /// Should be at most `MAX_ASSET_NAME_LENGTH` (32) bytes long
+  pub fn asset_name(mut self, asset_name: &str) -> AssetParamsBuilder {
+    self.asset_name = Some(asset_name.into());
+    self
+  }
+
+  /// Should be at most `MAX_ASSET_URL_LENGTH` (96) bytes long
+  pub fn url(mut self, url: &str) -> AssetParamsBuilder {
+    self.url = Some(url.into());
+    self
+  }
+
+  /// Should be exactly `ASSET_METADATA_HASH_LENGTH` (32) bytes long
+  pub fn metadata_hash(mut self, metadata_hash: &[u8]) -> AssetParamsBuilder {
+    self.metadata_hash = Some(metadata_hash.to_vec());
+    self
+  }
+
+  /// Should be a checksumed address
+  pub fn clawback(mut self, clawback: &str) -> AssetParamsBuilder {
+    self.clawback = Some(clawback.into());
+    self
+  }
+
+  /// Should be at most `MAX_ASSET_DECIMALS` (19)
+  pub fn decimals(mut self, decimals: u64) -> AssetParamsBuilder {
+    self.decimals = Some(decimals);
+    self
+  }
+
+  pub fn default_frozen(mut self, default_frozen: bool) -> AssetParamsBuilder {
+    self.default_frozen = Some(default_frozen);
+    self
+  }
+
+  /// Should be a checksumed address
+  pub fn freeze(mut self, freeze: &str) -> AssetParamsBuilder {
+    self.freeze = Some(freeze.into());
+    self
+  }
+
+  /// Should be a checksumed address
+  pub fn manager(mut self, manager: &str) -> AssetParamsBuilder {
+    self.manager = Some(manager.into());
+    self
+  }
+
+  /// Should be a checksumed address
+  pub fn reserve(mut self, reserve: &str) -> AssetParamsBuilder {
+    self.reserve = Some(reserve.into());
+    self
+  }
+
+  pub fn total(mut self, total: u64) -> AssetParamsBuilder {
+    self.total = Some(total);
+    self
+  }
+
+  /// Should be at most `MAX_UNIT_NAME_LENGTH` (8) bytes long
+  pub fn unit_name(mut self, unit_name: &str) -> AssetParamsBuilder {
+    self.unit_name = Some(unit_name.into());
+    self
+  }
+
+  /// Validates the fields and builds the `AssetParams`.
+  /// 
+  /// It will return an `AlgorandSdkError::InvalidAssetParam` naming the field
+  /// if any field is too long or is not a valid address.
+  pub fn build(self) -> Result<AssetParams, Error> {
+    Ok(AssetParams {
+      metadata_hash: validate_metadata_hash(self.metadata_hash)?,
+      asset_name: validate_length("asset_name", self.asset_name, MAX_ASSET_NAME_LENGTH)?,
+      url: validate_length("url", self.url, MAX_ASSET_URL_LENGTH)?,
+      clawback: decode_address("clawback", self.clawback)?,
+      decimals: validate_decimals(self.decimals)?,
+      default_frozen: self.default_frozen,
+      freeze: decode_address("freeze", self.freeze)?,
+      manager: decode_address("manager", self.manager)?,
+      reserve: decode_address("reserve", self.reserve)?,
+      total: self.total,
+      unit_name: validate_length("unit_name", self.unit_name, MAX_UNIT_NAME_LENGTH)?,
+    })
+  }
+}
+
+fn validate_length(field: &str, value: Option<String>, max_length: usize) -> Result<Option<ByteBuf>, Error> {
+  match value {
+    Some(value) => {
+      if value.len() > max_length {
+        Err(AlgorandSdkError::InvalidAssetParam(
+          field.into(),
+          format!("should be at most {} bytes but got {}", max_length, value.len()),
+        ))?;
+      }
+      Ok(Some(ByteBuf::from(value.into_bytes())))
+    },
+    None => Ok(None),
+  }
+}
+
+fn validate_decimals(value: Option<u64>) -> Result<Option<u64>, Error> {
+  match value {
+    Some(decimals) if decimals > MAX_ASSET_DECIMALS => Err(AlgorandSdkError::InvalidAssetParam(
+      "decimals".into(),
+      format!("should be at most {} but got {}", MAX_ASSET_DECIMALS, decimals),
+    ))?,
+    value => Ok(value),
+  }
+}
+
+fn is_none_or_zero(value: &Option<u64>) -> bool {
+  value.unwrap_or(0) == 0
+}
+
+fn validate_metadata_hash(value: Option<Vec<u8>>) -> Result<Option<ByteBuf>, Error> {
+  match value {
+    Some(value) => {
+      if value.len() != ASSET_METADATA_HASH_LENGTH {
+        Err(AlgorandSdkError::InvalidAssetParam(
+          "metadata_hash".into(),
+          format!("should be {} bytes but got {}", ASSET_METADATA_HASH_LENGTH, value.len()),
+        ))?;
+      }
+      Ok(Some(ByteBuf::from(value)))
+    },
+    None => Ok(None),
+  }
+}
+
+fn decode_address(field: &str, value: Option<String>) -> Result<Option<ByteBuf>, Error> {
+  match value {
+    Some(value) => match Address::from_string(&value) {
+      Ok(address) => Ok(Some(ByteBuf::from(address.to_vec()))),
+      Err(err) => Err(AlgorandSdkError::InvalidAssetParam(field.into(), err.to_string()))?,
+    },
+    None => Ok(None),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{AssetParamsBuilder, AlgorandSdkError};
+  use crate::encoding::{base64_decode, rmp_encode};
+
+  fn assert_invalid_field(builder: AssetParamsBuilder, expected_field: &str) {
+    let actual_error = builder.build().unwrap_err();
+    match actual_error {
+      AlgorandSdkError::InvalidAssetParam(field, _) => assert_eq!(field, expected_field),
+      other => panic!("Unexpected error {:?}", other),
+    }
+  }
+
+  #[test]
+  fn asset_params_builder_works() {
+    let asset_params = AssetParamsBuilder::new()
+      .total(100)
+      .unit_name("12345678")
+      .asset_name("12345678901234567890123456789012")
+      .manager("47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU")
+      .build()
+      .unwrap();
+
+    assert_eq!(asset_params.total, Some(100));
+    assert_eq!(asset_params.unit_name.unwrap().to_vec(), b"12345678".to_vec());
+    assert_eq!(asset_params.manager.unwrap().len(), 32);
+    assert!(asset_params.reserve.is_none());
+  }
+
+  #[test]
+  fn asset_params_builder_rejects_long_unit_name() {
+    assert_invalid_field(AssetParamsBuilder::new().unit_name("123456789"), "unit_name");
+  }
+
+  #[test]
+  fn asset_params_builder_rejects_long_asset_name() {
+    assert_invalid_field(AssetParamsBuilder::new().asset_name("123456789012345678901234567890123"), "asset_name");
+  }
+
+  #[test]
+  fn nft_asset_params_encode_url_and_metadata_hash() {
+    let golden = "hqJhbcQgpOFEwK2RwpEcM7vusd/NTZb8cESeSZo9yYPrQJFGcRSiYW7EBk15IE5GVKJhdcQ6aXBmczovL1FtV1MxVkFkTUQzNTNBNlNEazl3Tnl2a1QxNGt5Q2lack5EWUFhZDR3MXRLcVQjYXJjM6FtxCDn8PhNBoEd+fMcjYeLEVX0Zx1RoYXCAJCGZ/RJWHBooaF0AaJ1bsQDTkZU";
+    let metadata_hash = base64_decode("pOFEwK2RwpEcM7vusd/NTZb8cESeSZo9yYPrQJFGcRQ=").unwrap();
+
+    let asset_params = AssetParamsBuilder::new()
+      .total(1)
+      .unit_name("NFT")
+      .asset_name("My NFT")
+      .url("ipfs://QmWS1VAdMD353A6SDk9wNyvkT14kyCiZrNDYAad4w1tKqT#arc3")
+      .metadata_hash(&metadata_hash)
+      .manager("47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU")
+      .build()
+      .unwrap();
+
+    assert_eq!(rmp_encode(&asset_params).unwrap(), base64_decode(golden).unwrap());
+  }
+
+  #[test]
+  fn asset_params_encode_decimals() {
+    let golden = "hKJhbsQFVG9rZW6iZGMGoXTOO5rKAKJ1bsQDVE9L";
+    let builder = AssetParamsBuilder::new()
+      .total(1_000_000_000)
+      .unit_name("TOK")
+      .asset_name("Token");
+
+    let asset_params = builder.clone().decimals(6).build().unwrap();
+    assert_eq!(rmp_encode(&asset_params).unwrap(), base64_decode(golden).unwrap());
+
+    // zero decimals are omitted like other zero values
+    let zero_decimals = builder.clone().decimals(0).build().unwrap();
+    assert_eq!(rmp_encode(&zero_decimals).unwrap(), rmp_encode(&builder.build().unwrap()).unwrap());
+
+    assert_invalid_field(AssetParamsBuilder::new().decimals(20), "decimals");
+  }
+
+  #[test]
+  fn asset_params_builder_rejects_invalid_url_and_metadata_hash() {
+    assert_invalid_field(AssetParamsBuilder::new().url(&"a".repeat(97)), "url");
+    assert_invalid_field(AssetParamsBuilder::new().metadata_hash(&[0; 31]), "metadata_hash");
+    assert!(AssetParamsBuilder::new().url(&"a".repeat(96)).build().is_ok());
+  }
+
+  #[test]
+  fn asset_params_builder_rejects_invalid_address() {
+    assert_invalid_field(AssetParamsBuilder::new().clawback("not an address"), "clawback");
+  }
 }
\ No newline at end of file
@@ -0,0 +1,189 @@
+use serde::{Serialize};
+use serde_bytes::ByteBuf;
+use sha2::{Digest, Sha512Trunc256};
+use super::{Transaction, SignedTransaction, TxType};
+use super::tx_type::DIGEST_BYTE_LENGTH;
+use crate::accounts::Account;
+use crate::errors::{Error, AlgorandSdkError};
+use crate::encoding::{base64_encode, rmp_encode};
+use crate::helpers::ToArray;
+
+/// Maximum number of transactions allowed in an atomic transaction group
+pub const MAX_TX_GROUP_SIZE: usize = 16;
+
+/// This is for internal use only. It is the encodable list of transaction ids
+/// hashed to get the group id.
+#[derive(Serialize)]
+struct TxGroup {
+  #[serde(rename = "txlist")]
+  tx_group_hashes: Vec<ByteBuf>,
+}
+
+/// Computes the group id for a list of transactions.
+///
+/// None of the transactions should have a group id assigned yet. It will return a `MismatchedGroupGenesis`
+/// Error if they don't all have the same genesis id and hash, as the network rejects such a group.
+pub fn compute_group_id(txns: &[Transaction]) -> Result<[u8; DIGEST_BYTE_LENGTH], Error> {
+  if txns.is_empty() {
+    Err(AlgorandSdkError::EmptyTransactionGroup())?;
+  }
+
+  if txns.len() > MAX_TX_GROUP_SIZE {
+    Err(AlgorandSdkError::TooManyTransactionsInGroup {
+      max: MAX_TX_GROUP_SIZE,
+      got: txns.len(),
+    })?;
+  }
+
+  let genesis = |txn: &Transaction| format!("{} ({})", txn.header.genesis_id, base64_encode(txn.header.genesis_hash));
+  for (index, txn) in txns.iter().enumerate().skip(1) {
+    if txn.header.genesis_id != txns[0].header.genesis_id || txn.header.genesis_hash != txns[0].header.genesis_hash {
+      Err(AlgorandSdkError::MismatchedGroupGenesis { index, expected: genesis(&txns[0]), got: genesis(txn) })?;
+    }
+  }
+
+  let mut tx_group_hashes: Vec<ByteBuf> = Vec::with_capacity(txns.len());
+  for txn in txns {
+    if txn.header.group.is_some() {
+      Err(AlgorandSdkError::GroupIdAlreadyAssigned())?;
+    }
+    tx_group_hashes.push(ByteBuf::from(txn.raw_id()?));
+  }
+
+  let mut bytes_to_hash: Vec<u8> = Vec::new();
+  bytes_to_hash.extend(b"TG");
+  bytes_to_hash.extend(rmp_encode(&TxGroup { tx_group_hashes })?);
+
+  let group_id = Sha512Trunc256::default().chain(bytes_to_hash).result();
+  Ok(group_id.as_slice().to_array())
+}
+
+/// Computes the group id for the transactions and returns them with the group id assigned.
+pub fn assign_group_id(txns: Vec<Transaction>) -> Result<Vec<Transaction>, Error> {
+  let group_id = compute_group_id(&txns)?;
+
+  Ok(txns.into_iter()
+    .map(|mut txn| {
+      txn.header.group = Some(group_id);
+      txn
+    })
+    .collect())
+}
+
+/// Groups a payment and an asset transfer, such as A paying algos to B for an asset B sends to A,
+/// so either both or neither are executed.
+///
+/// The transactions are returned in order with the group id assigned, ready to be signed by their senders.
+/// It will return an `InvalidAtomicSwap` Error if the transactions are not a payment and an asset transfer
+pub fn atomic_swap(payment: Transaction, asset_transfer: Transaction) -> Result<Vec<Transaction>, Error> {
+  for (txn, expected) in &[(&payment, TxType::Payment), (&asset_transfer, TxType::AssetTransfer)] {
+    if txn.tx_type != *expected {
+      Err(AlgorandSdkError::InvalidAtomicSwap { expected: expected.to_string(), got: txn.tx_type.to_string() })?;
+    }
+  }
+
+  assign_group_id(vec![payment, asset_transfer])
+}
+
+/// Collects transactions that should be executed atomically.
+///
+/// # Example
+/// ```rust
+/// # use rust_algorand_sdk::accounts::Account;
+/// # use rust_algorand_sdk::transaction::{AtomicTransactionGroup, MicroAlgos, PaymentTransactionInput, Transaction};
+/// # let payment = |from: &Account, to: &Account| Transaction::from_input(PaymentTransactionInput {
+/// #   from: from.address.to_string(),
+/// #   to: to.address.to_string(),
+/// #   fee: MicroAlgos(1000),
+/// #   amount: MicroAlgos(5000),
+/// #   first_round: 12466,
+/// #   last_round: 13466,
+/// #   note: None,
+/// #   close_remainder_to: None,
+/// #   allow_self_close: false,
+/// #   genesis_id: "devnet-v33.0".into(),
+/// #   genesis_hash: "JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI=".into(),
+/// #   is_flat_fee: true,
+/// # });
+/// let sender = Account::generate();
+/// let other_sender = Account::generate();
+///
+/// let mut group = AtomicTransactionGroup::new();
+/// group.add(payment(&sender, &other_sender)?)?.add(payment(&other_sender, &sender)?)?;
+///
+/// let (signed_txns, bytes) = group.sign(&[&sender, &other_sender])?;
+/// assert_eq!(signed_txns.len(), 2);
+/// // bytes can be sent over the network as is
+/// # Ok::<(), rust_algorand_sdk::errors::Error>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AtomicTransactionGroup {
+  transactions: Vec<Transaction>,
+}
+
+impl AtomicTransactionGroup {
+  pub fn new() -> AtomicTransactionGroup {
+    Default::default()
+  }
+
+  /// Adds a transaction to the group.
+  ///
+  /// The transaction is marked as a group member, so it can only be signed after
+  /// the group is built.
+  ///
+  /// It will return an Error if the group already has `MAX_TX_GROUP_SIZE` (16) transactions
+  pub fn add(&mut self, mut txn: Transaction) -> Result<&mut AtomicTransactionGroup, Error> {
+    if self.transactions.len() >= MAX_TX_GROUP_SIZE {
+      Err(AlgorandSdkError::TooManyTransactionsInGroup {
+        max: MAX_TX_GROUP_SIZE,
+        got: self.transactions.len() + 1,
+      })?;
+    }
+
+    txn.requires_group = true;
+    self.transactions.push(txn);
+    Ok(self)
+  }
+
+  /// Returns the transactions added so far. They don't have the group id assigned yet.
+  pub fn transactions(&self) -> &[Transaction] {
+    &self.transactions
+  }
+
+  pub fn len(&self) -> usize {
+    self.transactions.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.transactions.is_empty()
+  }
+
+  /// Returns the transactions in the order they were added with the group id assigned
+  pub fn build(&self) -> Result<Vec<Transaction>, Error> {
+    assign_group_id(self.transactions.clone())
+  }
+
+  /// Builds the group and signs each transaction with the account at the same position in `signers`.
+  ///
+  /// # Returns
+  /// A `Result` with okay value being the ordered signed transactions and the concatenation of their
+  /// encoded bytes, which is what should be sent over the network.
+  pub fn sign(&self, signers: &[&Account]) -> Result<(Vec<SignedTransaction>, Vec<u8>), Error> {
+    if signers.len() != self.transactions.len() {
+      Err(AlgorandSdkError::MismatchedGroupSigners {
+        expected: self.transactions.len(),
+        got: signers.len(),
+      })?;
+    }
+
+    let mut signed_txns: Vec<SignedTransaction> = Vec::with_capacity(signers.len());
+    let mut bytes: Vec<u8> = Vec::new();
+    for (txn, signer) in self.build()?.iter().zip(signers) {
+      let signed_txn = txn.sign(signer)?;
+      bytes.extend(signed_txn.encode()?);
+      signed_txns.push(signed_txn);
+    }
+
+    Ok((signed_txns, bytes))
+  }
+}
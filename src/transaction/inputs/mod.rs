@@ -1,10 +1,10 @@
-use super::{MINIMUM_TX_FEE, TxType, Transaction, TransactionHeader, PaymentTransactionParams, KeyRegTransactionParams, AssetConfigTransactionParams, MicroAlgos, Round};
+use super::{MINIMUM_TX_FEE, TxType, Transaction, TransactionHeader, PaymentTransactionParams, KeyRegTransactionParams, AssetConfigTransactionParams, AssetTransferTransactionParams, MicroAlgos, Round};
 use serde_bytes::ByteBuf;
-use super::tx_type::DIGEST_BYTE_LENGTH;
+use super::tx_type::{DIGEST_BYTE_LENGTH, STATE_PROOF_PK_BYTE_LENGTH};
 use super::asset::{AssetID, AssetParams};
 use crate::accounts::{Account, Signature, Address, PublicKeyBytes};
 use crate::errors::{Error, AlgorandSdkError};
-use crate::encoding::{base64_decode};
+use crate::encoding::{decode_base64};
 use crate::helpers::ToArray;
 
 pub trait TransactionInput {
@@ -22,26 +22,171 @@ pub trait TransactionInput {
     Ok(None)
   }
 
+  fn build_asset_transfer_params(&self) -> Result<Option<AssetTransferTransactionParams>, Error> {
+    Ok(None)
+  }
+
+  fn is_nonparticipating(&self) -> bool {
+    false
+  }
+
+  /// The fee per byte of the input, None when it uses a flat fee
+  fn fee_per_byte(&self) -> Option<MicroAlgos> {
+    None
+  }
+
   fn modify_final_transaction(&self, transaction: Transaction) -> Result<Transaction, Error> {
     Ok(transaction)
   }
 }
 
+/// Genesis hash of the network, either base64 encoded or as the raw bytes
+/// returned in the suggested params.
+#[derive(Clone, Debug)]
+pub enum GenesisHash {
+  Base64(String),
+  Bytes([u8; DIGEST_BYTE_LENGTH]),
+}
+
+impl GenesisHash {
+  /// Creates a genesis hash from raw bytes, such as a `Vec<u8>` returned by a client.
+  /// 
+  /// It will return an Error if the slice is empty or not `DIGEST_BYTE_LENGTH` (32) bytes long
+  pub fn from_bytes(genesis_hash: &[u8]) -> Result<GenesisHash, Error> {
+    if genesis_hash.is_empty() {
+      Err(AlgorandSdkError::MissingGenesisHash())?;
+    }
+
+    if genesis_hash.len() != DIGEST_BYTE_LENGTH {
+      Err(AlgorandSdkError::InvalidGenesisHashLength {
+        expected: DIGEST_BYTE_LENGTH,
+        got: genesis_hash.len(),
+      })?;
+    }
+    Ok(GenesisHash::Bytes(genesis_hash.to_array()))
+  }
+
+  /// Returns the raw bytes of the genesis hash, decoding it if necessary
+  pub fn to_bytes(&self) -> Result<[u8; DIGEST_BYTE_LENGTH], Error> {
+    match self {
+      GenesisHash::Bytes(bytes) => Ok(*bytes),
+      GenesisHash::Base64(encoded) => {
+        GenesisHash::from_bytes(&decode_base64(encoded)?)?.to_bytes()
+      },
+    }
+  }
+}
+
+impl From<String> for GenesisHash {
+  fn from(encoded: String) -> GenesisHash {
+    GenesisHash::Base64(encoded)
+  }
+}
+
+impl From<&str> for GenesisHash {
+  fn from(encoded: &str) -> GenesisHash {
+    GenesisHash::Base64(encoded.into())
+  }
+}
+
+impl From<[u8; DIGEST_BYTE_LENGTH]> for GenesisHash {
+  fn from(bytes: [u8; DIGEST_BYTE_LENGTH]) -> GenesisHash {
+    GenesisHash::Bytes(bytes)
+  }
+}
+
+/// The network parameters shared by the inputs of every transaction type.
+/// 
+/// - `fee` is fee per byte is is_flat_fee is false, else it is used as it.
+/// - `first_round` is the first round this txn is valid
+/// - `last_round` is the last round this txn is valid
+/// - `genesis_id` corresponds to the id of the network
+/// - `genesis_hash` corresponds to the hash of the genesis of the network, base64-encoded or raw bytes
+/// - `min_fee` is the minimum fee of the network, which can be more than `MINIMUM_TX_FEE`
+#[derive(Clone, Debug)]
+pub struct SuggestedParams {
+  pub fee: MicroAlgos,
+  pub first_round: u64,
+  pub last_round: u64,
+  pub genesis_id: String,
+  pub genesis_hash: GenesisHash,
+  pub is_flat_fee: bool,
+  pub min_fee: MicroAlgos,
+}
+
+impl SuggestedParams {
+  /// Computes the fee of `txn` with these params, raised to `min_fee` when lower.
+  /// 
+  /// Inputs only raise their fee to `MINIMUM_TX_FEE`, so set the fee of a transaction built
+  /// with these params to this one when the network minimum fee is higher
+  pub fn fee_for(&self, txn: &Transaction) -> Result<MicroAlgos, Error> {
+    if self.is_flat_fee {
+      Ok(self.fee.max(self.min_fee))
+    } else {
+      Ok(MicroAlgos(txn.estimate_fee(self.fee.0, self.min_fee.0)?))
+    }
+  }
+}
+
+/// Maximum byte length of a transaction note
+pub const MAX_NOTE_SIZE: usize = 1024;
+
+/// Maximum number of rounds between the first and last valid round of a transaction
+pub const MAX_VALIDITY_WINDOW: u64 = 1000;
+
+/// Computes the last valid round of a transaction valid for `window` rounds from `first_round`.
+/// 
+/// It will return an Error if `window` is more than `MAX_VALIDITY_WINDOW` (1000) or the last round overflows
+pub fn last_valid_round(first_round: Round, window: u64) -> Result<Round, Error> {
+  if window > MAX_VALIDITY_WINDOW {
+    Err(AlgorandSdkError::InvalidValidityWindow { max: MAX_VALIDITY_WINDOW, got: window })?;
+  }
+
+  first_round.add_window(window)
+}
+
+// implementation of set_validity for the inputs
+macro_rules! set_validity_impl {
+  ($input:ty) => {
+    impl $input {
+      /// Sets `first_round` and `last_round` so the transaction is valid for `window` rounds from `first_round`.
+      /// 
+      /// It will return an Error if `window` is more than `MAX_VALIDITY_WINDOW` (1000) or the last round overflows
+      pub fn set_validity(&mut self, first_round: Round, window: u64) -> Result<(), Error> {
+        self.last_round = last_valid_round(first_round, window)?.into();
+        self.first_round = first_round.into();
+        Ok(())
+      }
+    }
+  };
+}
+
+// implementation of with_note_string for the inputs
+macro_rules! note_string_impl {
+  ($input:ty) => {
+    impl $input {
+      /// Sets the note to the UTF-8 bytes of `note`, such as "invoice #123".
+      /// 
+      /// It will return a `NoteTooLong` Error if the note is more than `MAX_NOTE_SIZE` (1024) bytes
+      pub fn with_note_string(mut self, note: &str) -> Result<$input, Error> {
+        if note.len() > MAX_NOTE_SIZE {
+          Err(AlgorandSdkError::NoteTooLong { max: MAX_NOTE_SIZE, got: note.len() })?;
+        }
+        self.note = Some(note.as_bytes().to_vec());
+        Ok(self)
+      }
+    }
+  };
+}
+
 // default implementation for the build_header and modify_final_transactions
 macro_rules! build_header_impl {
   ($type:expr) => {
     fn build_header(&self) -> Result<(TxType, TransactionHeader), Error> {
-      let genesis_hash = base64_decode(&self.genesis_hash)?;
-      if genesis_hash.is_empty() {
-        return Err(AlgorandSdkError::GenericError("Genesis hash required".into()))?;
-      }
-
-      if genesis_hash.len() != DIGEST_BYTE_LENGTH {
-        return Err(AlgorandSdkError::GenericError(format!(
-          "Expected genesis hash to be {} bytes but got {}",
-          DIGEST_BYTE_LENGTH,
-          genesis_hash.len())),
-        )?;
+      if let Some(ref note) = self.note {
+        if note.len() > MAX_NOTE_SIZE {
+          Err(AlgorandSdkError::NoteTooLong { max: MAX_NOTE_SIZE, got: note.len() })?;
+        }
       }
 
       let header = TransactionHeader {
@@ -51,23 +196,24 @@ macro_rules! build_header_impl {
         last_valid: self.last_round,
         note: self.note.clone(),
         genesis_id: self.genesis_id.clone(),
-        genesis_hash: genesis_hash.to_array(),
+        genesis_hash: self.genesis_hash.to_bytes()?,
         group: None,
       };
 
-      
-
       Ok(($type, header))
     }
 
+    fn fee_per_byte(&self) -> Option<MicroAlgos> {
+      if self.is_flat_fee { None } else { Some(self.fee) }
+    }
+
     fn modify_final_transaction(&self, transaction: Transaction) -> Result<Transaction, Error> {
       let mut txn = transaction;
 
       if self.is_flat_fee {
         txn.header.fee = self.fee;
       } else {
-        let estimated_size = txn.estimate_size()?;
-        txn.header.fee = estimated_size * self.fee;
+        txn.header.fee = MicroAlgos(txn.estimate_fee(self.fee.0, MINIMUM_TX_FEE.0)?);
       }
 
       if txn.header.fee < MINIMUM_TX_FEE {
@@ -86,31 +232,63 @@ macro_rules! build_header_impl {
 /// - `last_round` is the last round this txn is valid
 /// - `note` is a byte array
 /// - `genesis_id` corresponds to the id of the network
-/// - `genesis_hash` corresponds to the base64-encoded hash of the genesis of the network
+/// - `genesis_hash` corresponds to the hash of the genesis of the network, base64-encoded or raw bytes
 /// - `to` is a checksumed, human readble address of the receipient of the payment
 /// - `amount` is the amount to be payment to the receipient in micro algos
 /// - `close_remainder_to` if specified, the from account will be closed and the remaining sent to the address specified here.
+/// - `allow_self_close` if true, `close_remainder_to` is allowed to be the same as the `from` address. This is almost always a mistake so it is rejected by default.
 pub struct PaymentTransactionInput {
   pub from: String,
   pub fee: MicroAlgos,
-  pub first_round: Round,
-  pub last_round: Round,
+  pub first_round: u64,
+  pub last_round: u64,
   pub note: Option<Vec<u8>>,
   pub genesis_id: String,
-  pub genesis_hash: String,
+  pub genesis_hash: GenesisHash,
   pub is_flat_fee: bool,
   // payment fields
   pub to: String,
   pub amount: MicroAlgos,
   pub close_remainder_to: Option<String>,
+  pub allow_self_close: bool,
 }
 
+impl PaymentTransactionInput {
+  /// Constructs the transaction closing the account `from`, sending its whole balance to `close_to`.
+  /// 
+  /// A close is a payment of zero micro algos to `close_to` with `close_remainder_to` set to it.
+  /// Building the transaction will return an Error if `close_to` is `from`
+  pub fn close_account(from: &str, close_to: &str, params: SuggestedParams) -> PaymentTransactionInput {
+    PaymentTransactionInput {
+      from: from.into(),
+      fee: params.fee,
+      first_round: params.first_round,
+      last_round: params.last_round,
+      note: None,
+      genesis_id: params.genesis_id,
+      genesis_hash: params.genesis_hash,
+      is_flat_fee: params.is_flat_fee,
+      to: close_to.into(),
+      amount: MicroAlgos(0),
+      close_remainder_to: Some(close_to.into()),
+      allow_self_close: false,
+    }
+  }
+}
+
+set_validity_impl!(PaymentTransactionInput);
+note_string_impl!(PaymentTransactionInput);
+
 impl TransactionInput for PaymentTransactionInput {
   build_header_impl!(TxType::Payment);
 
   fn build_payment_params(&self) -> Result<Option<PaymentTransactionParams>, Error> {
     let close_remainder_to_addr = if let Some(ref close_address) = self.close_remainder_to {
-      Some(Address::from_string(&close_address)?.into())
+      let close_addr = Address::from_string(close_address)?;
+      if !self.allow_self_close && close_addr.as_bytes() == Address::from_string(&self.from)?.as_bytes() {
+        Err(AlgorandSdkError::CloseToSender(self.from.clone()))?;
+      }
+      Some(close_addr.into())
     } else {
       None
     };
@@ -132,35 +310,73 @@ impl TransactionInput for PaymentTransactionInput {
 /// - `last_round` is the last round this txn is valid
 /// - `note` is a byte array
 /// - `genesis_id` corresponds to the id of the network
-/// - `genesis_hash` corresponds to the base64-encoded hash of the genesis of the network
+/// - `genesis_hash` corresponds to the hash of the genesis of the network, base64-encoded or raw bytes
 /// - `vote_pk` is base64 encoded string corresponding to the root participation public key
 /// - `selection_pk` is the base64 encoded string corresponding to the vrf public key
 /// - `vote_first` is the first round this participation key is valid
 /// - `vote_last` is the last round this participation key is valid
 /// - `vote_key_dilution` is the dilution for the 2-level pariticpation key
+/// - `state_proof_pk` is the base64 encoded string corresponding to the 64 bytes state proof public key
+/// - `nonparticipating` marks the account as non-participating. The participation keys should not be set
+///
+/// `vote_pk` and `selection_pk` should either both be set or both be `None`.
+/// When both are `None`, the account is marked offline and the vote fields are omitted.
 pub struct KeyRegTransactionInput {
   pub from: String,
   pub fee: MicroAlgos,
-  pub first_round: Round,
-  pub last_round: Round,
+  pub first_round: u64,
+  pub last_round: u64,
   pub note: Option<Vec<u8>>,
   pub genesis_id: String,
-  pub genesis_hash: String,
+  pub genesis_hash: GenesisHash,
   pub is_flat_fee: bool,
   // keyreg fields
-  pub vote_pk: String,
-  pub selection_pk: String,
-  pub vote_first: Round,
-  pub vote_last: Round,
+  pub vote_pk: Option<String>,
+  pub selection_pk: Option<String>,
+  pub vote_first: u64,
+  pub vote_last: u64,
   pub vote_key_dilution: u64,
+  pub state_proof_pk: Option<String>,
+  pub nonparticipating: bool,
 }
 
+set_validity_impl!(KeyRegTransactionInput);
+note_string_impl!(KeyRegTransactionInput);
+
 impl TransactionInput for KeyRegTransactionInput {
   build_header_impl!(TxType::KeyReg);
 
   fn build_key_reg_params(&self) -> Result<Option<KeyRegTransactionParams>, Error> {
-    let vote_pk = base64_decode(&self.vote_pk)?;
-    let selection_pk = base64_decode(&self.selection_pk)?;
+    let has_keys = self.vote_pk.is_some() || self.selection_pk.is_some() || self.state_proof_pk.is_some();
+    if self.nonparticipating && has_keys {
+      Err(AlgorandSdkError::InvalidKeyReg(
+        "Participation keys can't be set for a nonparticipating key registration".into()),
+      )?;
+    }
+
+    let (vote_pk, selection_pk) = match (self.vote_pk.as_ref(), self.selection_pk.as_ref()) {
+      (Some(vote_pk), Some(selection_pk)) => (decode_base64(vote_pk)?, decode_base64(selection_pk)?),
+      // going offline
+      (None, None) if self.state_proof_pk.is_none() => return Ok(None),
+      _ => Err(AlgorandSdkError::InvalidKeyReg(
+        "vote_pk and selection_pk should both be set, or both be None without a state_proof_pk".into()),
+      )?,
+    };
+
+    let state_proof_pk = match self.state_proof_pk.as_ref() {
+      Some(state_proof_pk) => {
+        let state_proof_pk = decode_base64(state_proof_pk)?;
+        if state_proof_pk.len() != STATE_PROOF_PK_BYTE_LENGTH {
+          Err(AlgorandSdkError::InvalidKeyReg(format!(
+            "state_proof_pk should be {} bytes but got {}",
+            STATE_PROOF_PK_BYTE_LENGTH,
+            state_proof_pk.len())),
+          )?;
+        }
+        Some(state_proof_pk.as_slice().to_array())
+      },
+      None => None,
+    };
     Ok(Some(
       KeyRegTransactionParams {
         vote_pk: vote_pk.to_array(),
@@ -168,9 +384,14 @@ impl TransactionInput for KeyRegTransactionInput {
         vote_first: self.vote_first,
         vote_last: self.vote_last,
         vote_key_dilution: self.vote_key_dilution,
+        state_proof_pk,
       }
     ))
   }
+
+  fn is_nonparticipating(&self) -> bool {
+    self.nonparticipating
+  }
 }
 
 /// Constructs a keyreg transactio using the fields as parameters
@@ -181,21 +402,25 @@ impl TransactionInput for KeyRegTransactionInput {
 /// - `last_round` is the last round this txn is valid
 /// - `note` is a byte array
 /// - `genesis_id` corresponds to the id of the network
-/// - `genesis_hash` corresponds to the base64-encoded hash of the genesis of the network
+/// - `genesis_hash` corresponds to the hash of the genesis of the network, base64-encoded or raw bytes
 /// - `creator` checksumed address of creator for this asset
 /// - `index` index representing the id
 /// - `manager` if present should be the checksumed address of the new manager
 /// - `reserve` if present should be the checksumed address of account whose holding of this asset is reported as "not minted"
 /// - `freeze` if present should be the checksumed address of account allowed to freeze holding of this asset
 /// - `clawback` if present should be a valid checksumed address
+/// 
+/// To permanently clear a role (`manager`, `reserve`, `freeze` or `clawback`), set it to the zero address
+/// (`AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKQ`). It is encoded as the zero address bytes,
+/// while a role that is `None` is left out of the encoded asset params.
 pub struct AssetConfigTransactionInput {
   pub from: String,
   pub fee: MicroAlgos,
-  pub first_round: Round,
-  pub last_round: Round,
+  pub first_round: u64,
+  pub last_round: u64,
   pub note: Option<Vec<u8>>,
   pub genesis_id: String,
-  pub genesis_hash: String,
+  pub genesis_hash: GenesisHash,
   pub is_flat_fee: bool,
   // asset config field
   pub creator: String,
@@ -206,6 +431,9 @@ pub struct AssetConfigTransactionInput {
   pub clawback: Option<String>,
 }
 
+set_validity_impl!(AssetConfigTransactionInput);
+note_string_impl!(AssetConfigTransactionInput);
+
 impl TransactionInput for AssetConfigTransactionInput {
   build_header_impl!(TxType::AssetConfig);
 
@@ -250,6 +478,112 @@ impl TransactionInput for AssetConfigTransactionInput {
   }
 }
 
+/// Constructs an asset transfer transaction using the fields as parameters
+/// 
+/// - `from` is a checksumed, human readable address of the sender
+/// - `fee` is fee per byte is is_flat_fee is false, else it is used as it.
+/// - `first_round` is the first round this txn is valid
+/// - `last_round` is the last round this txn is valid
+/// - `note` is a byte array
+/// - `genesis_id` corresponds to the id of the network
+/// - `genesis_hash` corresponds to the hash of the genesis of the network, base64-encoded or raw bytes
+/// - `asset_id` is the id of the asset being transferred
+/// - `to` is a checksumed, human readable address of the receipient of the asset
+/// - `amount` is the number of units of the asset to transfer
+/// - `revocation_target` if specified, the units are revoked from this address. The sender should be the asset clawback account
+/// - `close_assets_to` if specified, the sender holding of the asset is closed and the remaining units sent to this address
+/// 
+/// Use [`AssetTransferTransactionInput::opt_in()`] to opt in to receiving an asset
+pub struct AssetTransferTransactionInput {
+  pub from: String,
+  pub fee: MicroAlgos,
+  pub first_round: u64,
+  pub last_round: u64,
+  pub note: Option<Vec<u8>>,
+  pub genesis_id: String,
+  pub genesis_hash: GenesisHash,
+  pub is_flat_fee: bool,
+  // asset transfer fields
+  pub asset_id: u64,
+  pub to: String,
+  pub amount: u64,
+  pub revocation_target: Option<String>,
+  pub close_assets_to: Option<String>,
+}
+
+impl AssetTransferTransactionInput {
+  /// Constructs the transaction opting `address` in to the asset, so it can receive it.
+  /// 
+  /// An opt in is a transfer of zero units of the asset from `address` to itself
+  pub fn opt_in(address: &str, asset_id: u64, params: SuggestedParams) -> AssetTransferTransactionInput {
+    AssetTransferTransactionInput {
+      from: address.into(),
+      fee: params.fee,
+      first_round: params.first_round,
+      last_round: params.last_round,
+      note: None,
+      genesis_id: params.genesis_id,
+      genesis_hash: params.genesis_hash,
+      is_flat_fee: params.is_flat_fee,
+      asset_id,
+      to: address.into(),
+      amount: 0,
+      revocation_target: None,
+      close_assets_to: None,
+    }
+  }
+
+  /// Constructs the transaction opting `address` out of the asset, sending its whole holding to `close_to`.
+  /// 
+  /// An opt out is a transfer of zero units of the asset to `close_to` with `close_assets_to` set to it.
+  /// Building the transaction will return an Error if `close_to` is not a valid address
+  pub fn opt_out(address: &str, asset_id: u64, close_to: &str, params: SuggestedParams) -> AssetTransferTransactionInput {
+    AssetTransferTransactionInput {
+      from: address.into(),
+      fee: params.fee,
+      first_round: params.first_round,
+      last_round: params.last_round,
+      note: None,
+      genesis_id: params.genesis_id,
+      genesis_hash: params.genesis_hash,
+      is_flat_fee: params.is_flat_fee,
+      asset_id,
+      to: close_to.into(),
+      amount: 0,
+      revocation_target: None,
+      close_assets_to: Some(close_to.into()),
+    }
+  }
+}
+
+set_validity_impl!(AssetTransferTransactionInput);
+note_string_impl!(AssetTransferTransactionInput);
+
+impl TransactionInput for AssetTransferTransactionInput {
+  build_header_impl!(TxType::AssetTransfer);
+
+  fn build_asset_transfer_params(&self) -> Result<Option<AssetTransferTransactionParams>, Error> {
+    let revocation_target = match self.revocation_target.as_ref() {
+      Some(address) => Some(Address::from_string(address)?.into()),
+      None => None,
+    };
+    let close_assets_to = match self.close_assets_to.as_ref() {
+      Some(address) => Some(Address::from_string(address)?.into()),
+      None => None,
+    };
+
+    Ok(Some(
+      AssetTransferTransactionParams {
+        asset_id: self.asset_id,
+        amount: self.amount,
+        revocation_target,
+        receiver: Address::from_string(&self.to)?.into(),
+        close_assets_to,
+      }
+    ))
+  }
+}
+
 // TODO(perfectmak): Fix this macro to avoid repeating similar fields for inputs
 // macro_rules! transaction_input {
 //   ( @ $name:ident { ($($fields:tt)*) } ) => {
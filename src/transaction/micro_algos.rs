@@ -0,0 +1,200 @@
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::errors::{AlgorandSdkError, Error};
+
+/// Number of micro algos in one algo
+pub const MICRO_ALGOS_PER_ALGO: u64 = 1_000_000;
+const MICRO_ALGOS_DECIMALS: usize = 6;
+
+/// An amount of micro algos, the smallest unit of algos.
+///
+/// It is encoded as a plain integer, so it can be used in place of a `u64` amount.
+///
+/// # Example
+/// ```rust
+/// use rust_algorand_sdk::transaction::MicroAlgos;
+///
+/// let amount = MicroAlgos::from_algos(1.5).unwrap();
+/// assert_eq!(amount, MicroAlgos(1_500_000));
+/// assert_eq!(amount.to_algos(), 1.5);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MicroAlgos(pub u64);
+
+impl MicroAlgos {
+  /// Converts an amount of algos to micro algos.
+  /// 
+  /// It will return an Error if the amount is negative, not finite, overflows
+  /// or has a fraction finer than 1 micro algo
+  pub fn from_algos(algos: f64) -> Result<MicroAlgos, Error> {
+    if !algos.is_finite() || algos < 0.0 {
+      Err(AlgorandSdkError::InvalidAlgoAmount(algos.to_string(), "should be a positive number".into()))?;
+    }
+
+    // the shortest decimal representation of the amount is converted exactly,
+    // so amounts like 0.1 don't pick up floating point errors
+    let algos_str = algos.to_string();
+    let (whole, fraction) = match algos_str.find('.') {
+      Some(index) => (&algos_str[..index], &algos_str[index + 1..]),
+      None => (&algos_str[..], ""),
+    };
+
+    if fraction.len() > MICRO_ALGOS_DECIMALS {
+      Err(AlgorandSdkError::InvalidAlgoAmount(algos_str.clone(), "should not be finer than 1 micro algo".into()))?;
+    }
+
+    let fraction = format!("{:0<width$}", fraction, width = MICRO_ALGOS_DECIMALS);
+    let micro_algos = whole.parse::<u64>().ok()
+      .and_then(|whole| whole.checked_mul(MICRO_ALGOS_PER_ALGO))
+      .and_then(|micro_algos| micro_algos.checked_add(fraction.parse::<u64>().ok()?));
+    match micro_algos {
+      Some(micro_algos) => Ok(MicroAlgos(micro_algos)),
+      None => Err(AlgorandSdkError::InvalidAlgoAmount(algos_str, "overflowed".into()))?,
+    }
+  }
+
+  pub fn to_algos(self) -> f64 {
+    self.0 as f64 / MICRO_ALGOS_PER_ALGO as f64
+  }
+
+  /// Returns None if the sum overflows
+  pub fn checked_add(self, other: MicroAlgos) -> Option<MicroAlgos> {
+    self.0.checked_add(other.0).map(MicroAlgos)
+  }
+
+  /// Returns None if the difference is negative
+  pub fn checked_sub(self, other: MicroAlgos) -> Option<MicroAlgos> {
+    self.0.checked_sub(other.0).map(MicroAlgos)
+  }
+
+  /// Returns None if the product overflows
+  pub fn checked_mul(self, multiplier: u64) -> Option<MicroAlgos> {
+    self.0.checked_mul(multiplier).map(MicroAlgos)
+  }
+}
+
+impl From<u64> for MicroAlgos {
+  fn from(micro_algos: u64) -> MicroAlgos {
+    MicroAlgos(micro_algos)
+  }
+}
+
+impl From<MicroAlgos> for u64 {
+  fn from(micro_algos: MicroAlgos) -> u64 {
+    micro_algos.0
+  }
+}
+
+/// Panics if the sum overflows, even in release builds. Use `checked_add` to handle the overflow
+impl Add for MicroAlgos {
+  type Output = MicroAlgos;
+
+  fn add(self, other: MicroAlgos) -> MicroAlgos {
+    self.checked_add(other).expect("MicroAlgos addition overflowed")
+  }
+}
+
+impl AddAssign for MicroAlgos {
+  fn add_assign(&mut self, other: MicroAlgos) {
+    *self = *self + other;
+  }
+}
+
+/// Panics if the difference is negative, even in release builds. Use `checked_sub` to handle it
+impl Sub for MicroAlgos {
+  type Output = MicroAlgos;
+
+  fn sub(self, other: MicroAlgos) -> MicroAlgos {
+    self.checked_sub(other).expect("MicroAlgos subtraction overflowed")
+  }
+}
+
+impl SubAssign for MicroAlgos {
+  fn sub_assign(&mut self, other: MicroAlgos) {
+    *self = *self - other;
+  }
+}
+
+/// Panics if the product overflows, even in release builds. Use `checked_mul` to handle the overflow
+impl Mul<u64> for MicroAlgos {
+  type Output = MicroAlgos;
+
+  fn mul(self, multiplier: u64) -> MicroAlgos {
+    self.checked_mul(multiplier).expect("MicroAlgos multiplication overflowed")
+  }
+}
+
+impl Serialize for MicroAlgos {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(self.0)
+  }
+}
+
+impl<'de> Deserialize<'de> for MicroAlgos {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<MicroAlgos, D::Error> {
+    Ok(MicroAlgos(u64::deserialize(deserializer)?))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::MicroAlgos;
+  use crate::errors::AlgorandSdkError;
+  use crate::encoding::rmp_encode;
+
+  #[test]
+  fn test_algos_conversion() {
+    assert_eq!(MicroAlgos::from_algos(1.5).unwrap(), MicroAlgos(1_500_000));
+    assert_eq!(MicroAlgos::from_algos(0.1).unwrap(), MicroAlgos(100_000));
+    assert_eq!(MicroAlgos::from_algos(0.000001).unwrap(), MicroAlgos(1));
+    assert_eq!(MicroAlgos::from_algos(12.0).unwrap(), MicroAlgos(12_000_000));
+    assert_eq!(MicroAlgos(2_250_000).to_algos(), 2.25);
+  }
+
+  #[test]
+  fn test_from_algos_fails_for_invalid_amounts() {
+    for algos in &[1.0000001, -1.0, f64::NAN, f64::INFINITY, 1e20] {
+      let actual_error = MicroAlgos::from_algos(*algos).unwrap_err();
+      match actual_error {
+        AlgorandSdkError::InvalidAlgoAmount(_, _) => {},
+        other => panic!("Unexpected error {:?}", other),
+      }
+    }
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_add_panics_on_overflow() {
+    let _ = MicroAlgos(u64::MAX) + MicroAlgos(1);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_sub_panics_on_overflow() {
+    let _ = MicroAlgos(1) - MicroAlgos(2);
+  }
+
+  #[test]
+  #[should_panic]
+  fn test_mul_panics_on_overflow() {
+    let _ = MicroAlgos(u64::MAX) * 2;
+  }
+
+  #[test]
+  fn test_arithmetic() {
+    assert_eq!(MicroAlgos(1000) + MicroAlgos(500), MicroAlgos(1500));
+    assert_eq!(MicroAlgos(1000) - MicroAlgos(500), MicroAlgos(500));
+    assert_eq!(MicroAlgos(1000) * 3, MicroAlgos(3000));
+    assert_eq!(MicroAlgos(u64::MAX).checked_add(MicroAlgos(1)), None);
+    assert_eq!(MicroAlgos(1000).checked_sub(MicroAlgos(500)), Some(MicroAlgos(500)));
+    assert_eq!(MicroAlgos(1).checked_sub(MicroAlgos(2)), None);
+    assert_eq!(MicroAlgos(u64::MAX).checked_mul(2), None);
+  }
+
+  #[test]
+  fn test_encodes_as_integer() {
+    for value in &[0, 1000, 1_500_000, u64::MAX] {
+      assert_eq!(rmp_encode(&MicroAlgos(*value)).unwrap(), rmp_encode(value).unwrap());
+    }
+  }
+}
\ No newline at end of file
@@ -1,30 +1,60 @@
+mod application;
 mod asset;
 mod tx_type;
 mod inputs;
+mod group;
+mod micro_algos;
+mod round;
 
-use std::convert::TryInto;
+use std::fmt;
+use std::fs;
+use std::path::Path;
 use serde::{Deserialize, Serialize};
 use serde_bytes::ByteBuf;
 use sha2::{Digest, Sha512Trunc256};
-use asset::{AssetConfigTransactionParams};
-use tx_type::{RawTransaction, TransactionHeader, PaymentTransactionParams, KeyRegTransactionParams};
-use crate::accounts::{Account, Signature};
-use crate::errors::{Error};
-use crate::encoding::{rmp_encode, base32_encode};
+use ed25519_dalek::{Verifier, SIGNATURE_LENGTH};
+use asset::{AssetID, AssetConfigTransactionParams, AssetTransferTransactionParams};
+use tx_type::{TransactionHeader, PaymentTransactionParams, KeyRegTransactionParams};
+use crate::accounts::{Account, Address, AddressBytes, MultisigAccount, PublicKey, Signature, TX_SIGN_PREFIX};
+use crate::helpers::ToArray;
+use crate::errors::{Error, AlgorandSdkError};
+use crate::encoding::{rmp_encode, rmp_decode, rmp_decode_all, rmp_decode_json, base32_encode, base64_encode, decode_base64};
 
-pub use inputs::{AssetConfigTransactionInput, PaymentTransactionInput, KeyRegTransactionInput, TransactionInput};
-pub use tx_type::{TxType, MicroAlgos, Round};
+pub use inputs::{last_valid_round, MAX_VALIDITY_WINDOW, MAX_NOTE_SIZE, GenesisHash, SuggestedParams, AssetConfigTransactionInput, AssetTransferTransactionInput, PaymentTransactionInput, KeyRegTransactionInput, TransactionInput};
+pub use tx_type::{RawTransaction, TxType, DIGEST_BYTE_LENGTH};
+pub use micro_algos::{MicroAlgos, MICRO_ALGOS_PER_ALGO};
+pub use round::Round;
+pub use application::{AppArg, OnComplete};
+pub use asset::{AssetParams, AssetParamsBuilder, MAX_UNIT_NAME_LENGTH, MAX_ASSET_NAME_LENGTH, MAX_ASSET_URL_LENGTH, ASSET_METADATA_HASH_LENGTH, MAX_ASSET_DECIMALS};
+pub use group::{AtomicTransactionGroup, compute_group_id, assign_group_id, atomic_swap, MAX_TX_GROUP_SIZE};
 
-const MINIMUM_TX_FEE: u64 = 1000;
+/// Minimum fee of a transaction. Lower fees are raised to this value when building a transaction
+/// 
+/// # Example
+/// ```rust
+/// use rust_algorand_sdk::transaction::{MINIMUM_TX_FEE, DIGEST_BYTE_LENGTH};
+/// 
+/// assert_eq!(MINIMUM_TX_FEE.0, 1000);
+/// assert_eq!(DIGEST_BYTE_LENGTH, 32);
+/// ```
+pub const MINIMUM_TX_FEE: MicroAlgos = MicroAlgos(1000);
 
 /// Transaction describes a transaction that can appear in a block.
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct Transaction {
   pub tx_type: TxType,
   pub header: TransactionHeader,
   pub payment_params: Option<PaymentTransactionParams>,
   pub key_reg_params: Option<KeyRegTransactionParams>,
   pub asset_config_params: Option<AssetConfigTransactionParams>,
+  pub asset_transfer_params: Option<AssetTransferTransactionParams>,
+
+  /// Set on key registrations that mark the account as non-participating
+  pub nonparticipating: bool,
+
+  /// Set when the transaction is added to an `AtomicTransactionGroup`, so it
+  /// can't be signed until it has a group id assigned
+  pub(crate) requires_group: bool,
 }
 
 impl Transaction {
@@ -37,28 +67,161 @@ impl Transaction {
       payment_params: input.build_payment_params()?,
       key_reg_params: input.build_key_reg_params()?,
       asset_config_params: input.build_asset_config_params()?,
+      asset_transfer_params: input.build_asset_transfer_params()?,
+      nonparticipating: input.is_nonparticipating(),
+      requires_group: false,
     };
 
     Ok(input.modify_final_transaction(txn)?)
   }
 
+  /// Constructs a new transaction like [`Transaction::from_input()`], but rejects a fee per byte of 0.
+  /// 
+  /// `from_input` raises such a fee to `MINIMUM_TX_FEE`, which hides a fee that was left unset.
+  /// It will return a `ZeroFeePerByte` Error if `is_flat_fee` is false and `fee` is 0
+  pub fn from_input_strict<T: TransactionInput>(input: T) -> Result<Transaction, Error> {
+    if input.fee_per_byte() == Some(MicroAlgos(0)) {
+      Err(AlgorandSdkError::ZeroFeePerByte())?;
+    }
+    Transaction::from_input(input)
+  }
+
+  /// Constructs payment transactions from the inputs and assigns them a group id
+  /// so they are executed atomically.
+  ///
+  /// It will return an Error if there are more than `MAX_TX_GROUP_SIZE` (16) inputs
+  pub fn payment_group(inputs: Vec<PaymentTransactionInput>) -> Result<Vec<Transaction>, Error> {
+    if inputs.len() > MAX_TX_GROUP_SIZE {
+      Err(AlgorandSdkError::TooManyTransactionsInGroup {
+        max: MAX_TX_GROUP_SIZE,
+        got: inputs.len(),
+      })?;
+    }
+
+    let txns = inputs.into_iter()
+      .map(Transaction::from_input)
+      .collect::<Result<Vec<Transaction>, Error>>()?;
+
+    assign_group_id(txns)
+  }
+
+  /// Returns the group id of this transaction if it belongs to a transaction group
+  pub fn group_id(&self) -> Option<[u8; DIGEST_BYTE_LENGTH]> {
+    self.header.group
+  }
+
+  /// Sets the group id of this transaction.
+  /// 
+  /// This is useful when the group id was computed elsewhere, else use `assign_group_id`
+  pub fn set_group_id(&mut self, group_id: [u8; DIGEST_BYTE_LENGTH]) {
+    self.header.group = Some(group_id);
+  }
+
   // Signs this transaction with the account/private key provided
   pub fn sign(&self, account: &Account) -> Result<SignedTransaction, Error> {
-    let bytes_to_sign = self.to_raw_bytes()?;
-    let signature = account.sign(bytes_to_sign.as_ref());
+    self.check_group_assigned()?;
 
-    // compute id from signature
-    let checksum = Sha512Trunc256::default().chain(bytes_to_sign).result();
-    let txn_id = base32_encode(checksum.as_ref());
+    let signature = account.sign_with_prefix(TX_SIGN_PREFIX, &rmp_encode(&self.to_raw())?);
 
     Ok(SignedTransaction {
-      txn_id,
-      signature,
+      txn_id: self.id()?,
+      signature: Some(signature),
       transaction: self.to_raw(),
       multisig_sig: None,
+      auth_address: None,
     })
   }
 
+  /// Signs this transaction with `signer`, the auth address of a rekeyed sender.
+  /// 
+  /// The signed transaction records `auth_address` as its signer when it differs from the sender.
+  /// It will return an Error if `signer` is not the account of `auth_address`
+  pub fn sign_with_auth(&self, signer: &Account, auth_address: &Address) -> Result<SignedTransaction, Error> {
+    if signer.address != *auth_address {
+      Err(AlgorandSdkError::MismatchedAuthAddress(signer.address.to_string(), auth_address.to_string()))?;
+    }
+
+    let mut signed_txn = self.sign(signer)?;
+    if auth_address.as_bytes() != self.header.sender {
+      signed_txn.auth_address = Some(ByteBuf::from(auth_address.to_vec()));
+    }
+    Ok(signed_txn)
+  }
+
+  /// Signs this transaction with `account` on behalf of `multisig_account`.
+  /// 
+  /// Only the subsig of `account` holds a signature, the signed transactions of the other
+  /// participants have to be merged to meet the threshold.
+  /// It will return a `NotMultisigParticipant` Error if `account` is not a participant of `multisig_account`,
+  /// or an Error if the transaction sender is not the multisig address.
+  pub fn sign_multisig(&self, multisig_account: &MultisigAccount, account: &Account) -> Result<SignedTransaction, Error> {
+    self.check_group_assigned()?;
+
+    let signer_index = match multisig_account.index_of(&account.public_key()) {
+      Some(index) => index,
+      None => Err(AlgorandSdkError::NotMultisigParticipant(account.address.to_string()))?,
+    };
+
+    if multisig_account.address().as_bytes() != self.header.sender {
+      Err(AlgorandSdkError::InvalidMultisigAccount(
+        "Transaction sender is not the multisig account address".into()),
+      )?;
+    }
+
+    let signature = account.sign_with_prefix(TX_SIGN_PREFIX, &rmp_encode(&self.to_raw())?);
+    let subsigs = multisig_account.public_keys().iter().enumerate()
+      .map(|(index, public_key)| MultisigSubsig {
+        key: ByteBuf::from(public_key.to_bytes().to_vec()),
+        signature: if index == signer_index { Some(ByteBuf::from(signature.to_bytes().to_vec())) } else { None },
+      })
+      .collect();
+
+    Ok(SignedTransaction {
+      txn_id: self.id()?,
+      signature: None,
+      transaction: self.to_raw(),
+      multisig_sig: Some(MultisigSig {
+        subsigs,
+        threshold: multisig_account.threshold(),
+        version: multisig_account.version(),
+      }),
+      auth_address: None,
+    })
+  }
+
+  // Transactions added to a group can only be signed after the group id is assigned
+  fn check_group_assigned(&self) -> Result<(), Error> {
+    if self.requires_group && self.header.group.is_none() {
+      Err(AlgorandSdkError::MissingGroupId())?;
+    }
+    Ok(())
+  }
+
+  /// Returns the total micro algos leaving the sender account, which is the payment amount plus the fee.
+  /// 
+  /// It can be checked against the sender balance before signing.
+  /// It will return an Error if the sum overflows
+  pub fn total_outflow(&self) -> Result<MicroAlgos, Error> {
+    let amount = self.payment_params.as_ref().map(|p| p.amount).unwrap_or_default();
+    match amount.checked_add(self.header.fee) {
+      Some(total) => Ok(total),
+      None => Err(AlgorandSdkError::AmountOverflow())?,
+    }
+  }
+
+  /// Computes the id of this transaction.
+  /// 
+  /// It is the same as the `txn_id` of the `SignedTransaction` produced when this transaction is signed
+  pub fn id(&self) -> Result<String, Error> {
+    Ok(base32_encode(self.raw_id()?.as_ref()))
+  }
+
+  // Get the sha512/256 checksum of the raw bytes of this transaction
+  fn raw_id(&self) -> Result<Vec<u8>, Error> {
+    let checksum = Sha512Trunc256::default().chain(self.to_raw_bytes()?).result();
+    Ok(checksum.to_vec())
+  }
+
   // Get raw bytes from encoding this transaction
   // The returned byte can be signed for a signed transaction
   pub fn to_raw_bytes(&self) -> Result<Vec<u8>, Error> {
@@ -66,20 +229,95 @@ impl Transaction {
     Ok(Transaction::with_encode_tag(&rmp_encode(&raw_txn)?))
   }
 
+  /// Returns the bytes an external signer, such as a hardware wallet, should sign.
+  /// 
+  /// Combine the signature with these bytes using [`SignedTransaction::from_unsigned_and_signature()`]
+  pub fn to_unsigned_bytes(&self) -> Result<Vec<u8>, Error> {
+    self.to_raw_bytes()
+  }
+
   fn with_encode_tag(bytes: &Vec<u8>) -> Vec<u8> {
     let mut tag: Vec<u8> = Vec::new();
-    tag.extend(b"TX");
+    tag.extend(TX_SIGN_PREFIX);
     tag.extend(bytes);
     tag
   }
 
+  /// Estimates the fee of this transaction without signing it, so it can be shown before signing.
+  /// 
+  /// The fee is `fee_per_byte` times the estimated signed size, raised to `min_fee` when lower.
+  /// It matches the fee a transaction input with `is_flat_fee` set to false computes
+  pub fn estimate_fee(&self, fee_per_byte: u64, min_fee: u64) -> Result<u64, Error> {
+    let mut txn = self.clone();
+    txn.header.fee = MicroAlgos(fee_per_byte);
+    let estimated_size = txn.estimate_size()?;
+    match fee_per_byte.checked_mul(estimated_size) {
+      Some(fee) => Ok(fee.max(min_fee)),
+      None => Err(AlgorandSdkError::FeeOverflow())?,
+    }
+  }
+
+  /// Decodes an unsigned transaction sent as base64 msgpack, such as a WalletConnect signing request.
+  /// 
+  /// The `TX` tag is stripped when present, so the output of [`Transaction::to_unsigned_bytes()`]
+  /// can be decoded too
+  pub fn from_msgpack_base64(encoded: &str) -> Result<Transaction, Error> {
+    let bytes = decode_base64(encoded)?;
+    let raw_bytes = match bytes.strip_prefix(TX_SIGN_PREFIX) {
+      Some(raw_bytes) => raw_bytes,
+      None => &bytes[..],
+    };
+    Transaction::from_raw(rmp_decode(raw_bytes)?)
+  }
+
+  /// Sets the fee of this transaction, raising it to `MINIMUM_TX_FEE` when lower.
+  /// 
+  /// Changing the fee changes the transaction id, so the transaction has to be signed again.
+  /// It will return a `GroupIdAlreadyAssigned` Error if the transaction has a group id, since
+  /// the group id is computed from the transaction ids and would no longer match
+  pub fn set_flat_fee(&mut self, fee: MicroAlgos) -> Result<(), Error> {
+    self.check_fee_changeable()?;
+    self.header.fee = fee.max(MINIMUM_TX_FEE);
+    Ok(())
+  }
+
+  /// Recomputes the fee of this transaction from `fee_per_byte` and its estimated size,
+  /// like [`Transaction::estimate_fee()`] with a minimum fee of `MINIMUM_TX_FEE`.
+  /// 
+  /// Changing the fee changes the transaction id, so the transaction has to be signed again.
+  /// It will return a `GroupIdAlreadyAssigned` Error if the transaction has a group id
+  pub fn recompute_fee(&mut self, fee_per_byte: MicroAlgos) -> Result<(), Error> {
+    self.check_fee_changeable()?;
+    self.header.fee = MicroAlgos(self.estimate_fee(fee_per_byte.0, MINIMUM_TX_FEE.0)?);
+    Ok(())
+  }
+
+  // The fee of a group member has to be set before the group id is computed from it
+  fn check_fee_changeable(&self) -> Result<(), Error> {
+    if self.header.group.is_some() {
+      Err(AlgorandSdkError::GroupIdAlreadyAssigned())?;
+    }
+    Ok(())
+  }
+
   /// Estimates the byte size of transaction when sent
   fn estimate_size(&self) -> Result<u64, Error> {
-    let account = Account::generate();
-    let signed_txn = self.sign(&account)?;
+    // signatures have a fixed size, so a dummy one avoids signing, which group members
+    // can't do before their group id is assigned
+    let mut transaction = self.to_raw();
+    if self.requires_group && transaction.group.is_none() {
+      transaction.group = Some(ByteBuf::from(vec![0; DIGEST_BYTE_LENGTH]));
+    }
+    let signed_txn = SignedTransaction {
+      txn_id: String::new(),
+      signature: Some(Signature::from_bytes(&[0; SIGNATURE_LENGTH])?),
+      transaction,
+      multisig_sig: None,
+      auth_address: None,
+    };
 
     let signed_txn_bytes = signed_txn.encode()?;
-    Ok(signed_txn_bytes.len().try_into()?)
+    Ok(signed_txn_bytes.len() as u64)
   }
 
   // Convert `Transaction` to `RawTransaction` that is encodable
@@ -102,10 +340,15 @@ impl Transaction {
       votefst: self.key_reg_params.as_ref().map(|k| k.vote_first),
       votelst: self.key_reg_params.as_ref().map(|k| k.vote_last),
       votekd: self.key_reg_params.as_ref().map(|k| k.vote_key_dilution),
+      sprfkey: self.key_reg_params.as_ref()
+        .and_then(|k| k.state_proof_pk)
+        .map(|pk| ByteBuf::from(pk.to_vec())),
+      nonpart: self.nonparticipating,
 
       // payment fields
       receiver: self.payment_params.as_ref().map(|p| ByteBuf::from(p.receiver.to_vec())),
-      amount: self.payment_params.as_ref().map(|p| p.amount),
+      // zero amounts are omitted like every zero value of the canonical encoding
+      amount: self.payment_params.as_ref().map(|p| p.amount).filter(|amount| amount.0 != 0),
       // extract inner remainder too. would be easier if flatten() was stable
       close_remainder_to: match self.payment_params.as_ref() {
         Some(p) => match p.close_remainder_to {
@@ -116,7 +359,10 @@ impl Transaction {
       },
 
       // asset txn fields
-      asset_id: self.asset_config_params.as_ref().map(|a| a.asset_id.clone()),
+      // an asset creation has no asset id yet, so its empty id is left out rather than encoded as zero
+      asset_id: self.asset_config_params.as_ref()
+        .map(|a| a.asset_id.clone())
+        .filter(|asset_id| asset_id.index != 0 || !asset_id.creator.is_empty()),
       asset_params: match self.asset_config_params.as_ref() {
         Some(a) => match a.asset_params.as_ref() {
           Some(asset_params) => Some(asset_params.clone()),
@@ -124,23 +370,181 @@ impl Transaction {
         },
         _ => None,
       },
+
+      // asset transfer fields
+      xaid: self.asset_transfer_params.as_ref().map(|a| a.asset_id),
+      // a zero amount is omitted like the other empty fields
+      aamt: self.asset_transfer_params.as_ref().map(|a| a.amount).filter(|amount| *amount != 0),
+      asnd: self.asset_transfer_params.as_ref()
+        .and_then(|a| a.revocation_target)
+        .map(|addr| ByteBuf::from(addr.to_vec())),
+      arcv: self.asset_transfer_params.as_ref().map(|a| ByteBuf::from(a.receiver.to_vec())),
+      aclose: self.asset_transfer_params.as_ref()
+        .and_then(|a| a.close_assets_to)
+        .map(|addr| ByteBuf::from(addr.to_vec())),
     };
 
     raw_txn
   }
+
+  /// Reconstructs a `Transaction` from a decoded `RawTransaction`, such as the one of [`SignedTransaction::transaction()`].
+  /// 
+  /// Zero values are not encoded, so absent fields of the transaction type are read as zero.
+  /// It will return an `InvalidEncoding` Error if an address, key or hash has the wrong length
+  pub fn from_raw(raw: RawTransaction) -> Result<Transaction, Error> {
+    let zero_address: AddressBytes = Default::default();
+    let header = TransactionHeader {
+      sender: to_fixed_bytes("snd", &raw.sender)?,
+      fee: raw.fee,
+      first_valid: raw.first_valid,
+      last_valid: raw.last_valid,
+      note: raw.note.map(|n| n.into_vec()),
+      genesis_id: raw.genesis_id,
+      genesis_hash: GenesisHash::from_bytes(&raw.genesis_hash)?.to_bytes()?,
+      group: match raw.group {
+        Some(group) => Some(to_fixed_bytes("grp", &group)?),
+        None => None,
+      },
+    };
+
+    let payment_params = match raw.tx_type {
+      TxType::Payment => Some(PaymentTransactionParams {
+        receiver: match raw.receiver {
+          Some(receiver) => to_fixed_bytes("rcv", &receiver)?,
+          None => zero_address,
+        },
+        amount: raw.amount.unwrap_or_default(),
+        close_remainder_to: match raw.close_remainder_to {
+          Some(close) => Some(to_fixed_bytes("close", &close)?),
+          None => None,
+        },
+      }),
+      _ => None,
+    };
+
+    // offline key registrations have no participation keys
+    let key_reg_params = match (raw.tx_type, raw.votekey) {
+      (TxType::KeyReg, Some(votekey)) => Some(KeyRegTransactionParams {
+        vote_pk: to_fixed_bytes("votekey", &votekey)?,
+        selection_pk: match raw.selkey {
+          Some(selkey) => to_fixed_bytes("selkey", &selkey)?,
+          None => [0; 32],
+        },
+        vote_first: raw.votefst.unwrap_or(0),
+        vote_last: raw.votelst.unwrap_or(0),
+        vote_key_dilution: raw.votekd.unwrap_or(0),
+        state_proof_pk: match raw.sprfkey {
+          Some(sprfkey) => Some(to_fixed_bytes("sprfkey", &sprfkey)?),
+          None => None,
+        },
+      }),
+      _ => None,
+    };
+
+    let asset_config_params = match raw.tx_type {
+      TxType::AssetConfig => Some(AssetConfigTransactionParams {
+        asset_id: raw.asset_id.unwrap_or(AssetID { creator: Vec::new(), index: 0 }),
+        asset_params: raw.asset_params,
+      }),
+      _ => None,
+    };
+
+    let asset_transfer_params = match raw.tx_type {
+      TxType::AssetTransfer => Some(AssetTransferTransactionParams {
+        asset_id: raw.xaid.unwrap_or(0),
+        amount: raw.aamt.unwrap_or(0),
+        revocation_target: match raw.asnd {
+          Some(asnd) => Some(to_fixed_bytes("asnd", &asnd)?),
+          None => None,
+        },
+        receiver: match raw.arcv {
+          Some(arcv) => to_fixed_bytes("arcv", &arcv)?,
+          None => zero_address,
+        },
+        close_assets_to: match raw.aclose {
+          Some(aclose) => Some(to_fixed_bytes("aclose", &aclose)?),
+          None => None,
+        },
+      }),
+      _ => None,
+    };
+
+    Ok(Transaction {
+      tx_type: raw.tx_type,
+      header,
+      payment_params,
+      key_reg_params,
+      asset_config_params,
+      asset_transfer_params,
+      nonparticipating: raw.nonpart,
+      requires_group: false,
+    })
+  }
+}
+
+/// Transactions are equal when their raw, encodable forms are equal
+impl PartialEq for Transaction {
+  fn eq(&self, other: &Transaction) -> bool {
+    self.to_raw() == other.to_raw()
+  }
+}
+
+/// Shows addresses as checksum address strings and hashes as base64, instead of byte arrays
+impl fmt::Debug for Transaction {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let address = |bytes: AddressBytes| Address::from_fixed_bytes(bytes).to_string();
+
+    let mut debug = f.debug_struct("Transaction");
+    debug
+      .field("tx_type", &self.tx_type.to_string())
+      .field("sender", &address(self.header.sender))
+      .field("fee", &self.header.fee)
+      .field("first_valid", &self.header.first_valid)
+      .field("last_valid", &self.header.last_valid)
+      .field("note", &self.header.note)
+      .field("genesis_id", &self.header.genesis_id)
+      .field("genesis_hash", &base64_encode(self.header.genesis_hash))
+      .field("group", &self.header.group.map(base64_encode));
+
+    if let Some(ref payment) = self.payment_params {
+      debug
+        .field("receiver", &address(payment.receiver))
+        .field("amount", &payment.amount)
+        .field("close_remainder_to", &payment.close_remainder_to.map(address));
+    }
+    if let Some(ref key_reg) = self.key_reg_params {
+      debug.field("key_reg_params", key_reg);
+    }
+    if self.nonparticipating {
+      debug.field("nonparticipating", &self.nonparticipating);
+    }
+    if let Some(ref asset_config) = self.asset_config_params {
+      debug.field("asset_config_params", asset_config);
+    }
+    if let Some(ref asset_transfer) = self.asset_transfer_params {
+      debug
+        .field("asset_id", &asset_transfer.asset_id)
+        .field("asset_amount", &asset_transfer.amount)
+        .field("asset_receiver", &address(asset_transfer.receiver))
+        .field("revocation_target", &asset_transfer.revocation_target.map(address))
+        .field("close_assets_to", &asset_transfer.close_assets_to.map(address));
+    }
+    debug.finish()
+  }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct MultisigSubsig {
   #[serde(rename = "pk")]
   pub key: ByteBuf, // ed25519 public key
 
-  #[serde(rename = "s")]
-  pub signature: ByteBuf,
+  /// None when the owner of the key hasn't signed yet
+  #[serde(rename = "s", skip_serializing_if = "Option::is_none")]
+  pub signature: Option<ByteBuf>,
 }
 
 /// MultisigSig holds multiple Subsigs, as well as threshold and version info
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct MultisigSig {
   #[serde(rename = "subsig")]
   pub subsigs: Vec<MultisigSubsig>,
@@ -152,15 +556,31 @@ pub struct MultisigSig {
   pub version: u8,
 }
 
+impl MultisigSig {
+  /// Encodes the multisig signature on its own, without the transaction
+  pub fn encode(&self) -> Result<Vec<u8>, Error> {
+    Ok(rmp_encode(self)?)
+  }
+
+  pub fn decode(bytes: &[u8]) -> Result<MultisigSig, Error> {
+    Ok(rmp_decode(bytes)?)
+  }
+}
+
 /// SignedTransaction wraps a transaction and a signature. The rmp encoding of this 
 /// struct is suitable to broadcast on the network
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct SignedTransaction {
   #[serde(rename = "msig", skip_serializing_if = "Option::is_none")]
-  pub multisig_sig: Option<ByteBuf>,
+  pub multisig_sig: Option<MultisigSig>,
 
-  #[serde(rename = "sig")]
-  pub signature: Signature,
+  /// Set when the transaction is signed by the auth address of a rekeyed sender
+  #[serde(rename = "sgnr", default, skip_serializing_if = "Option::is_none")]
+  pub auth_address: Option<ByteBuf>,
+
+  /// None when the transaction is signed with a multisig
+  #[serde(rename = "sig", default, with = "serde_bytes", skip_serializing_if = "Option::is_none")]
+  pub signature: Option<Signature>,
 
   #[serde(rename = "txn")]
   transaction: RawTransaction,
@@ -173,6 +593,257 @@ impl SignedTransaction {
   pub fn encode(&self) -> Result<Vec<u8>, Error> {
     Ok(rmp_encode(self)?)
   }
+
+  /// Returns the encoded fields of the signed transaction, such as its amount and receiver
+  pub fn transaction(&self) -> &RawTransaction {
+    &self.transaction
+  }
+
+  /// Returns the unsigned transaction, such as one decoded from the network, as a `Transaction`
+  pub fn to_transaction(&self) -> Result<Transaction, Error> {
+    Transaction::from_raw(self.transaction.clone())
+  }
+
+  /// Returns the unsigned transaction without any of its signatures, so it can be signed again,
+  /// such as when the signatures of a multisig are collected separately.
+  /// 
+  /// It is the same as [`SignedTransaction::to_transaction()`]
+  pub fn to_unsigned(&self) -> Result<Transaction, Error> {
+    self.to_transaction()
+  }
+
+  /// Returns the group id of the transaction if it belongs to a transaction group
+  pub fn group_id(&self) -> Option<&[u8]> {
+    self.transaction.group.as_ref().map(|group| group.as_ref())
+  }
+
+  /// Verifies the multisig signature of this transaction.
+  /// 
+  /// The multisig preimage must hash to the sender address, every signature present must be
+  /// valid and the number of signatures must meet the threshold.
+  /// It will return a `NotMultisigSigned` Error if the transaction is not multisig signed.
+  pub fn verify_multisig(&self) -> Result<bool, Error> {
+    let multisig_sig = match self.multisig_sig.as_ref() {
+      Some(multisig_sig) => multisig_sig,
+      None => Err(AlgorandSdkError::NotMultisigSigned())?,
+    };
+
+    let public_keys = multisig_sig.subsigs.iter()
+      .map(|subsig| PublicKey::from_bytes(&subsig.key))
+      .collect::<Result<Vec<PublicKey>, _>>()?;
+    let multisig_account = MultisigAccount::new(multisig_sig.version, multisig_sig.threshold, public_keys)?;
+    if multisig_account.address().as_bytes() != self.authorizer() {
+      return Ok(false);
+    }
+
+    let signed_bytes = Transaction::with_encode_tag(&rmp_encode(&self.transaction)?);
+    let mut signature_count: usize = 0;
+    for (subsig, public_key) in multisig_sig.subsigs.iter().zip(multisig_account.public_keys()) {
+      if let Some(ref signature) = subsig.signature {
+        let signature = Signature::from_bytes(signature)?;
+        if public_key.verify(&signed_bytes, &signature).is_err() {
+          return Ok(false);
+        }
+        signature_count += 1;
+      }
+    }
+
+    Ok(signature_count >= multisig_sig.threshold as usize)
+  }
+
+  /// Adds the signature of `account` to this multisig signed transaction, such as when the
+  /// partially signed transaction is passed from one signer to the next.
+  /// 
+  /// It will return a `NotMultisigSigned` Error if the transaction is not multisig signed, a `NotMultisigParticipant`
+  /// Error if `account` is not a participant and a `MultisigAlreadySigned` Error if its signature is already present.
+  pub fn append_multisig_signature(&mut self, account: &Account) -> Result<(), Error> {
+    let multisig_sig = match self.multisig_sig.as_mut() {
+      Some(multisig_sig) => multisig_sig,
+      None => Err(AlgorandSdkError::NotMultisigSigned())?,
+    };
+
+    let public_key = account.public_key();
+    let subsig = match multisig_sig.subsigs.iter_mut().find(|subsig| subsig.key.as_slice() == public_key.as_bytes()) {
+      Some(subsig) => subsig,
+      None => Err(AlgorandSdkError::NotMultisigParticipant(account.address.to_string()))?,
+    };
+
+    if subsig.signature.is_some() {
+      Err(AlgorandSdkError::MultisigAlreadySigned(account.address.to_string()))?;
+    }
+
+    let signature = account.sign_with_prefix(TX_SIGN_PREFIX, &rmp_encode(&self.transaction)?);
+    subsig.signature = Some(ByteBuf::from(signature.to_bytes().to_vec()));
+    Ok(())
+  }
+
+  /// Writes the encoded signed transaction to the file at `path`, in the same format as goal's `.stxn` files
+  pub fn write_to<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+    SignedTransaction::write_all_to(std::slice::from_ref(self), path)
+  }
+
+  /// Writes the encoded signed transactions concatenated to the file at `path`.
+  /// 
+  /// This is the format goal uses for transaction groups, so the file can be sent with `goal clerk rawsend`
+  pub fn write_all_to<P: AsRef<Path>>(signed_txns: &[SignedTransaction], path: P) -> Result<(), Error> {
+    let mut bytes: Vec<u8> = Vec::new();
+    for signed_txn in signed_txns {
+      bytes.extend(signed_txn.encode()?);
+    }
+    fs::write(path, bytes)?;
+    Ok(())
+  }
+
+  /// Reads one or more concatenated encoded signed transactions from the file at `path`,
+  /// such as the `.stxn` files written by goal
+  pub fn read_all_from<P: AsRef<Path>>(path: P) -> Result<Vec<SignedTransaction>, Error> {
+    let bytes = fs::read(path)?;
+    let mut signed_txns: Vec<SignedTransaction> = rmp_decode_all(&bytes)?;
+    for signed_txn in signed_txns.iter_mut() {
+      signed_txn.txn_id = signed_txn.compute_txn_id()?;
+    }
+    Ok(signed_txns)
+  }
+
+  /// Reads the single encoded signed transaction in the file at `path`, such as a file written by `write_to`.
+  /// 
+  /// It will return a `NotSingleSignedTransaction` Error if the file holds more than one transaction,
+  /// use `read_all_from` for groups
+  pub fn read_from<P: AsRef<Path>>(path: P) -> Result<SignedTransaction, Error> {
+    let mut signed_txns = SignedTransaction::read_all_from(path)?;
+    if signed_txns.len() != 1 {
+      Err(AlgorandSdkError::NotSingleSignedTransaction(signed_txns.len()))?;
+    }
+    Ok(signed_txns.remove(0))
+  }
+
+  /// Creates a signed transaction from `txn` and the signature an external signer produced over
+  /// its [`Transaction::to_unsigned_bytes()`], without needing the private key.
+  /// 
+  /// Raw 64 byte signatures can be converted with `Signature::from_bytes`, which validates their length.
+  /// It will return an Error if the signature is not valid for the transaction sender
+  pub fn from_unsigned(txn: &Transaction, signature: Signature) -> Result<SignedTransaction, Error> {
+    txn.check_group_assigned()?;
+    SignedTransaction::from_unsigned_and_signature(&txn.to_unsigned_bytes()?, signature)
+  }
+
+  /// Creates a signed transaction from the bytes returned by [`Transaction::to_unsigned_bytes()`]
+  /// and the signature an external signer produced for them.
+  /// 
+  /// It will return an Error if the bytes are not an unsigned transaction or the signature
+  /// is not valid for the transaction sender
+  pub fn from_unsigned_and_signature(unsigned_bytes: &[u8], signature: Signature) -> Result<SignedTransaction, Error> {
+    if !unsigned_bytes.starts_with(TX_SIGN_PREFIX) {
+      Err(AlgorandSdkError::InvalidEncoding(
+        "unsigned transaction".into(),
+        "bytes should start with the TX prefix".into()),
+      )?;
+    }
+
+    let transaction: RawTransaction = rmp_decode(&unsigned_bytes[TX_SIGN_PREFIX.len()..])?;
+    let mut signed_txn = SignedTransaction {
+      multisig_sig: None,
+      auth_address: None,
+      signature: Some(signature),
+      transaction,
+      txn_id: String::new(),
+    };
+    signed_txn.txn_id = signed_txn.compute_txn_id()?;
+
+    let public_key = PublicKey::from_bytes(&signed_txn.transaction.sender)?;
+    if public_key.verify(unsigned_bytes, &signature).is_err() {
+      Err(AlgorandSdkError::InvalidTransactionSignature(signed_txn.txn_id.clone()))?;
+    }
+    Ok(signed_txn)
+  }
+
+  /// Decodes a signed transaction and verifies its signature, so the returned transaction can be trusted.
+  /// 
+  /// It will return an `InvalidSignedTransactionEncoding` Error if the bytes can't be decoded and an
+  /// `InvalidTransactionSignature` Error if the single or multisig signature is not valid
+  pub fn decode_and_verify(bytes: &[u8]) -> Result<SignedTransaction, Error> {
+    let mut signed_txn: SignedTransaction = match rmp_decode(bytes) {
+      Ok(signed_txn) => signed_txn,
+      Err(err) => Err(AlgorandSdkError::InvalidSignedTransactionEncoding(err.to_string()))?,
+    };
+    signed_txn.txn_id = signed_txn.compute_txn_id()?;
+
+    if !signed_txn.verify().unwrap_or(false) {
+      Err(AlgorandSdkError::InvalidTransactionSignature(signed_txn.txn_id.clone()))?;
+    }
+    Ok(signed_txn)
+  }
+
+  /// Verifies the signature of this transaction against the sender, or the auth address
+  /// of a rekeyed sender when it is set. Multisig signatures are verified with `verify_multisig`.
+  /// 
+  /// It will return an Error if the signer is not a valid public key
+  pub fn verify(&self) -> Result<bool, Error> {
+    match (self.signature.as_ref(), self.multisig_sig.is_some()) {
+      (Some(signature), false) => {
+        let public_key = PublicKey::from_bytes(self.authorizer())?;
+        let signed_bytes = Transaction::with_encode_tag(&rmp_encode(&self.transaction)?);
+        Ok(public_key.verify(&signed_bytes, signature).is_ok())
+      },
+      (None, true) => self.verify_multisig(),
+      _ => Ok(false),
+    }
+  }
+
+  // The address whose key signed the transaction, the auth address of a rekeyed sender or the sender
+  fn authorizer(&self) -> &[u8] {
+    match self.auth_address.as_ref() {
+      Some(auth_address) => auth_address.as_slice(),
+      None => self.transaction.sender.as_slice(),
+    }
+  }
+
+  // Recomputes the id of a decoded transaction since it isn't part of the encoding
+  pub(crate) fn compute_txn_id(&self) -> Result<String, Error> {
+    let raw_bytes = Transaction::with_encode_tag(&rmp_encode(&self.transaction)?);
+    let checksum = Sha512Trunc256::default().chain(raw_bytes).result();
+    Ok(base32_encode(checksum.as_slice()))
+  }
+
+  /// Renders the signed transaction as json for debugging and logging.
+  /// 
+  /// Byte fields are rendered as base64 strings and the transaction id is included as `txid`
+  pub fn to_json(&self) -> Result<String, Error> {
+    let mut json_value = rmp_decode_json(&self.encode()?)?;
+    json_value["txid"] = self.txn_id.clone().into();
+    Ok(json_value.to_string())
+  }
+}
+
+/// This is for internal use only. Captures only the type of an encoded signed transaction
+#[derive(Deserialize)]
+struct SignedTransactionType {
+  txn: TransactionType,
+}
+
+#[derive(Deserialize)]
+struct TransactionType {
+  #[serde(with = "tx_type", rename = "type")]
+  tx_type: TxType,
+}
+
+/// Reads the transaction type from encoded signed transaction bytes
+/// without decoding the rest of the transaction.
+pub fn peek_tx_type(bytes: &[u8]) -> Result<TxType, Error> {
+  let signed_txn: SignedTransactionType = rmp_decode(bytes)?;
+  Ok(signed_txn.txn.tx_type)
+}
+
+// Copies a decoded byte field into a fixed size array, checking its length
+fn to_fixed_bytes<T>(field: &str, bytes: &[u8]) -> Result<T, Error> where for<'a> &'a [u8]: ToArray<T> {
+  let expected_length = std::mem::size_of::<T>();
+  if bytes.len() != expected_length {
+    Err(AlgorandSdkError::InvalidEncoding(
+      field.into(),
+      format!("should be {} bytes but got {}", expected_length, bytes.len())),
+    )?;
+  }
+  Ok(bytes.to_array())
 }
 
 #[cfg(test)]
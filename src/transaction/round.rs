@@ -0,0 +1,75 @@
+use std::fmt;
+use crate::errors::{AlgorandSdkError, Error};
+
+/// A round of the chain.
+///
+/// The round fields of transactions are plain `u64`s, `Round` converts from and to them
+/// so functions taking a round can't be passed a fee or an amount by mistake.
+///
+/// # Example
+/// ```rust
+/// use rust_algorand_sdk::transaction::Round;
+///
+/// let first_round = Round::from(20000);
+/// let last_round: u64 = first_round.add_window(1000).unwrap().into();
+/// assert_eq!(last_round, 21000);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Round(pub u64);
+
+impl Round {
+  /// Returns the round `window` rounds after this one.
+  ///
+  /// It will return a `RoundOverflow` Error if the round overflows
+  pub fn add_window(self, window: u64) -> Result<Round, Error> {
+    match self.0.checked_add(window) {
+      Some(round) => Ok(Round(round)),
+      None => Err(AlgorandSdkError::RoundOverflow())?,
+    }
+  }
+}
+
+impl From<u64> for Round {
+  fn from(round: u64) -> Round {
+    Round(round)
+  }
+}
+
+impl From<Round> for u64 {
+  fn from(round: Round) -> u64 {
+    round.0
+  }
+}
+
+impl fmt::Display for Round {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::Round;
+  use crate::errors::AlgorandSdkError;
+
+  #[test]
+  fn test_add_window() {
+    assert_eq!(Round(20000).add_window(1000).unwrap(), Round(21000));
+    assert_eq!(Round(u64::MAX - 10).add_window(10).unwrap(), Round(u64::MAX));
+
+    for (round, window) in &[(u64::MAX, 1), (u64::MAX - 10, 11), (1, u64::MAX)] {
+      match Round(*round).add_window(*window).unwrap_err() {
+        AlgorandSdkError::RoundOverflow() => {},
+        other => panic!("Unexpected error {:?}", other),
+      }
+    }
+  }
+
+  #[test]
+  fn test_converts_from_and_to_u64() {
+    let round: Round = 12466.into();
+    assert_eq!(round, Round(12466));
+    assert_eq!(u64::from(round), 12466);
+    assert_eq!(round.to_string(), "12466");
+  }
+}
\ No newline at end of file
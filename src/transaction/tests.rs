@@ -1,8 +1,10 @@
 // Unit tests for the transactions module
 
-use crate::encoding::base64_decode;
-use super::{Transaction, PaymentTransactionInput, KeyRegTransactionInput, AssetConfigTransactionInput};
-use crate::accounts::Account;
+use crate::encoding::{base64_decode, base64_encode, rmp_decode, rmp_encode};
+use serde_bytes::ByteBuf;
+use super::{Transaction, SignedTransaction, MultisigSig, MultisigSubsig, TxType, AtomicTransactionGroup, assign_group_id, atomic_swap, peek_tx_type, PaymentTransactionInput, KeyRegTransactionInput, AssetConfigTransactionInput, AssetTransferTransactionInput, AssetParamsBuilder, SuggestedParams, GenesisHash, MicroAlgos, Round, MINIMUM_TX_FEE};
+use crate::accounts::{Account, Address, MultisigAccount, PublicKey};
+use crate::errors::AlgorandSdkError;
 
 #[test]
 fn test_make_payment_transaction_works() {
@@ -20,12 +22,13 @@ fn test_make_payment_transaction_works() {
   let txn = Transaction::from_input(PaymentTransactionInput {
     from: from_address.into(),
     to: to_address.into(),
-    fee: 4,
-    amount: 1000,
+    fee: MicroAlgos(4),
+    amount: MicroAlgos(1000),
     first_round: 12466,
     last_round: 13466,
     note: Some(note),
     close_remainder_to: Some(close_remainder_to.into()),
+    allow_self_close: false,
     genesis_id: "devnet-v33.0".into(),
     genesis_hash: gh.into(),
     is_flat_fee: false,
@@ -38,6 +41,98 @@ fn test_make_payment_transaction_works() {
   assert_eq!(signed_txn.txn_id, expected_reference_txn_id);
 }
 
+#[test]
+fn test_estimate_fee_matches_built_fee() {
+  let txn = Transaction::from_input(PaymentTransactionInput {
+    from: "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU".into(),
+    to: "PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI".into(),
+    fee: MicroAlgos(10),
+    amount: MicroAlgos(1000),
+    first_round: 12466,
+    last_round: 13466,
+    note: Some(vec![7; 32]),
+    close_remainder_to: None,
+    allow_self_close: false,
+    genesis_id: "devnet-v33.0".into(),
+    genesis_hash: "JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI=".into(),
+    is_flat_fee: false,
+  }).unwrap();
+
+  assert!(txn.header.fee.0 > 1000);
+  assert_eq!(txn.estimate_fee(10, 1000).unwrap(), txn.header.fee.0);
+  assert_eq!(txn.estimate_fee(1, 1000).unwrap(), 1000);
+
+  let actual_error = txn.estimate_fee(u64::MAX, 0).unwrap_err();
+  match actual_error {
+    AlgorandSdkError::FeeOverflow() => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_zero_fee_per_byte_is_raised_or_rejected() {
+  let account = Account::generate();
+  let receiver = Account::generate();
+  let zero_fee_input = || PaymentTransactionInput {
+    fee: MicroAlgos(0),
+    is_flat_fee: false,
+    ..payment_input(&account, &receiver, 1000)
+  };
+
+  let txn = Transaction::from_input(zero_fee_input()).unwrap();
+  assert_eq!(txn.header.fee, MINIMUM_TX_FEE);
+
+  let actual_error = Transaction::from_input_strict(zero_fee_input()).unwrap_err();
+  match actual_error {
+    AlgorandSdkError::ZeroFeePerByte() => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+
+  // a zero flat fee is explicit, so it is raised to the minimum fee
+  let flat_fee_input = PaymentTransactionInput { is_flat_fee: true, ..zero_fee_input() };
+  assert_eq!(Transaction::from_input_strict(flat_fee_input).unwrap().header.fee, MINIMUM_TX_FEE);
+}
+
+#[test]
+fn test_fee_bump() {
+  let account = Account::generate();
+  let mut txn = Transaction::from_input(payment_input(&account, &Account::generate(), 1000)).unwrap();
+  let txn_id = txn.id().unwrap();
+
+  txn.set_flat_fee(MicroAlgos(2000)).unwrap();
+  assert_eq!(txn.to_raw().fee, MicroAlgos(2000));
+  assert_ne!(txn.id().unwrap(), txn_id);
+  let signed_txn = SignedTransaction::decode_and_verify(&txn.sign(&account).unwrap().encode().unwrap()).unwrap();
+  assert_eq!(signed_txn.to_transaction().unwrap().header.fee, MicroAlgos(2000));
+
+  txn.set_flat_fee(MicroAlgos(10)).unwrap();
+  assert_eq!(txn.header.fee, MINIMUM_TX_FEE);
+
+  txn.recompute_fee(MicroAlgos(20)).unwrap();
+  assert_eq!(txn.header.fee.0, txn.estimate_fee(20, MINIMUM_TX_FEE.0).unwrap());
+  assert!(txn.header.fee > MINIMUM_TX_FEE);
+}
+
+#[test]
+fn test_fee_bump_fails_once_group_id_assigned() {
+  let account_a = Account::generate();
+  let account_b = Account::generate();
+  let mut txns = assign_group_id(vec![
+    Transaction::from_input(payment_input(&account_a, &account_b, 5000)).unwrap(),
+    Transaction::from_input(payment_input(&account_b, &account_a, 3000)).unwrap(),
+  ]).unwrap();
+
+  match txns[0].set_flat_fee(MicroAlgos(2000)).unwrap_err() {
+    AlgorandSdkError::GroupIdAlreadyAssigned() => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+  match txns[1].recompute_fee(MicroAlgos(20)).unwrap_err() {
+    AlgorandSdkError::GroupIdAlreadyAssigned() => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+  assert_eq!(txns[0].header.fee, MicroAlgos(1000));
+}
+
 #[test]
 fn test_key_reg_transaction_works() {
   let mnemonic = "advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor";
@@ -47,18 +142,20 @@ fn test_key_reg_transaction_works() {
 
   let txn = Transaction::from_input(KeyRegTransactionInput {
     from: "BH55E5RMBD4GYWXGX5W5PJ5JAHPGM5OXKDQH5DC4O2MGI7NW4H6VOE4CP4".into(),
-    fee: 10,
+    fee: MicroAlgos(10),
     first_round: 322575,
     last_round: 323575,
     note: Some(([45, 67]).to_vec()),
     genesis_id: "".into(),
-    genesis_hash: String::from("SGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiI="),
-    vote_pk: "Kv7QI7chi1y6axoy+t7wzAVpePqRq/rkjzWh/RMYyLo=".into(),
-    selection_pk: "bPgrv4YogPcdaUAxrt1QysYZTVyRAuUMD4zQmCu9llc=".into(),
+    genesis_hash: "SGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiI=".into(),
+    vote_pk: Some("Kv7QI7chi1y6axoy+t7wzAVpePqRq/rkjzWh/RMYyLo=".into()),
+    selection_pk: Some("bPgrv4YogPcdaUAxrt1QysYZTVyRAuUMD4zQmCu9llc=".into()),
     vote_first: 10000,
     vote_last: 10111,
     vote_key_dilution: 11,
     is_flat_fee: false,
+    state_proof_pk: None,
+    nonparticipating: false,
   }).unwrap();
 
   println!("Debug {:?}", txn.to_raw());
@@ -69,6 +166,152 @@ fn test_key_reg_transaction_works() {
   assert_eq!(signed_txn.txn_id, "MDRIUVH5AW4Z3GMOB67WP44LYLEVM2MP3ZEPKFHUB5J47A2J6TUQ");
 }
 
+fn offline_key_reg_input(vote_pk: Option<String>, selection_pk: Option<String>) -> KeyRegTransactionInput {
+  KeyRegTransactionInput {
+    from: "BH55E5RMBD4GYWXGX5W5PJ5JAHPGM5OXKDQH5DC4O2MGI7NW4H6VOE4CP4".into(),
+    fee: MicroAlgos(1000),
+    first_round: 322575,
+    last_round: 323575,
+    note: None,
+    genesis_id: "".into(),
+    genesis_hash: "SGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiI=".into(),
+    vote_pk,
+    selection_pk,
+    vote_first: 0,
+    vote_last: 0,
+    vote_key_dilution: 0,
+    is_flat_fee: true,
+    state_proof_pk: None,
+    nonparticipating: false,
+  }
+}
+
+#[test]
+fn test_key_reg_transaction_with_state_proof_pk_works() {
+  let mnemonic = "advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor";
+  let golden = "gqNzaWfEQAS1LwhOMUjf4hKVJjLKvQfTKoJHR84HZzm8af4ByvewUUx2hcr8/w22NkzQMmreMZZNKhaXL5KHYB9fyw6lxwqjdHhujKNmZWXNA+iiZnbOAATsD6JnaMQgSGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiKibHbOAATv96ZzZWxrZXnEIGz4K7+GKID3HWlAMa7dUMrGGU1ckQLlDA+M0JgrvZZXo3NuZMQgCfvSdiwI+Gxa5r9t16epAd5mdddQ4H6MXHaYZH224f2nc3ByZmtlecRAmYR0GVEObMTSNdsKM6RwYywHYPqVDqg3E4JFzxZOreH9NU8B+tKzUanyY8AQ144hETgSMX7fXWwjBdHz6AWk96R0eXBlpmtleXJlZ6d2b3RlZnN0zScQpnZvdGVrZAundm90ZWtlecQgKv7QI7chi1y6axoy+t7wzAVpePqRq/rkjzWh/RMYyLqndm90ZWxzdM0nfw==";
+  let expected_signed_bytes = base64_decode(golden).unwrap();
+  let account = Account::from_mnemonic(mnemonic).unwrap();
+
+  let txn = Transaction::from_input(KeyRegTransactionInput {
+    vote_first: 10000,
+    vote_last: 10111,
+    vote_key_dilution: 11,
+    state_proof_pk: Some("mYR0GVEObMTSNdsKM6RwYywHYPqVDqg3E4JFzxZOreH9NU8B+tKzUanyY8AQ144hETgSMX7fXWwjBdHz6AWk9w==".into()),
+    ..offline_key_reg_input(
+      Some("Kv7QI7chi1y6axoy+t7wzAVpePqRq/rkjzWh/RMYyLo=".into()),
+      Some("bPgrv4YogPcdaUAxrt1QysYZTVyRAuUMD4zQmCu9llc=".into()),
+    )
+  }).unwrap();
+
+  let signed_txn = txn.sign(&account).unwrap();
+  assert_eq!(signed_txn.encode().unwrap(), expected_signed_bytes);
+  assert_eq!(signed_txn.txn_id, "NP2RCWOPSNQE223FX3SBJ52M3YVXTSXXZRCU4LOHUB2DBGYFZBRA");
+}
+
+#[test]
+fn test_key_reg_transaction_fails_with_invalid_state_proof_pk() {
+  let actual_error = Transaction::from_input(KeyRegTransactionInput {
+    state_proof_pk: Some("Kv7QI7chi1y6axoy+t7wzAVpePqRq/rkjzWh/RMYyLo=".into()),
+    ..offline_key_reg_input(
+      Some("Kv7QI7chi1y6axoy+t7wzAVpePqRq/rkjzWh/RMYyLo=".into()),
+      Some("bPgrv4YogPcdaUAxrt1QysYZTVyRAuUMD4zQmCu9llc=".into()),
+    )
+  }).err().unwrap();
+
+  match actual_error {
+    AlgorandSdkError::InvalidKeyReg(_) => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_offline_key_reg_transaction_works() {
+  let mnemonic = "advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor";
+  let golden = "gqNzaWfEQBZX2QZNs0AsYN4JPH5XR1qPADoKXztlHQMkOJrX/hE5fPFEn3eZ328zKhZppvmKnnKSmo5J4Y8aH+TwmBYALA+jdHhuhqNmZWXNA+iiZnbOAATsD6JnaMQgSGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiKibHbOAATv96NzbmTEIAn70nYsCPhsWua/bdenqQHeZnXXUOB+jFx2mGR9tuH9pHR5cGWma2V5cmVn";
+  let expected_signed_bytes = base64_decode(golden).unwrap();
+  let account = Account::from_mnemonic(mnemonic).unwrap();
+
+  let txn = Transaction::from_input(offline_key_reg_input(None, None)).unwrap();
+  assert!(txn.key_reg_params.is_none());
+
+  let signed_txn = txn.sign(&account).unwrap();
+  assert_eq!(signed_txn.encode().unwrap(), expected_signed_bytes);
+  assert_eq!(signed_txn.txn_id, "G5C3CNAI56VFRA5BBZGSZP2EFZCXL7HZ23KHJ4TK2CHZG77ZPK3A");
+}
+
+#[test]
+fn test_signed_transaction_from_unsigned() {
+  let mnemonic = "advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor";
+  let golden = "gqNzaWfEQOB1ccTL224PZAtHxHKSgyjsWHVh1O68JZSbJrwFimqfeGJ7gTsc9rBulFSTW+qr537FBwyTRcAbUC6NJJk41AujdHhuhqNmZWXNA+iiZnbOAATsD6JnaMQgSGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiKibHbOAATv96NzbmTEIOfw+E0GgR358xyNh4sRVfRnHVGhhcIAkIZn9ElYcGihpHR5cGWma2V5cmVn";
+  let account = Account::from_mnemonic(mnemonic).unwrap();
+  let mut input = offline_key_reg_input(None, None);
+  input.from = account.address.to_string();
+  let txn = Transaction::from_input(input).unwrap();
+
+  let signature = account.sign(&txn.to_unsigned_bytes().unwrap());
+  let signed_txn = SignedTransaction::from_unsigned(&txn, signature).unwrap();
+
+  assert_eq!(signed_txn.encode().unwrap(), base64_decode(golden).unwrap());
+  assert_eq!(signed_txn.txn_id, "ZS4PAGTII7CEWZZOIE6VWAYWRV5WQO57PRGI7VD5WB2OIEOE7B5Q");
+}
+
+#[test]
+fn test_signed_transaction_from_unsigned_fails_for_other_signer() {
+  let txn = Transaction::from_input(offline_key_reg_input(None, None)).unwrap();
+  let signature = Account::generate().sign(&txn.to_unsigned_bytes().unwrap());
+
+  let actual_error = SignedTransaction::from_unsigned(&txn, signature).unwrap_err();
+  match actual_error {
+    AlgorandSdkError::InvalidTransactionSignature(_) => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_key_reg_transaction_fails_with_only_one_key() {
+  let input = offline_key_reg_input(Some("Kv7QI7chi1y6axoy+t7wzAVpePqRq/rkjzWh/RMYyLo=".into()), None);
+  let actual_error = Transaction::from_input(input).err().unwrap();
+
+  match actual_error {
+    AlgorandSdkError::InvalidKeyReg(_) => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_nonparticipating_key_reg_transaction_works() {
+  let mnemonic = "advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor";
+  let golden = "gqNzaWfEQAPwUWLH9rKzAWRaAH6qwWmS41VjbnCU9h8BwnNscz5mb72ntmx0U/UC/yD+PwjEhPClfQF15BRIukHRgTfflAOjdHhuh6NmZWXNA+iiZnbOAATsD6JnaMQgSGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiKibHbOAATv96dub25wYXJ0w6NzbmTEIAn70nYsCPhsWua/bdenqQHeZnXXUOB+jFx2mGR9tuH9pHR5cGWma2V5cmVn";
+  let expected_signed_bytes = base64_decode(golden).unwrap();
+  let account = Account::from_mnemonic(mnemonic).unwrap();
+
+  let txn = Transaction::from_input(KeyRegTransactionInput {
+    nonparticipating: true,
+    ..offline_key_reg_input(None, None)
+  }).unwrap();
+
+  let signed_txn = txn.sign(&account).unwrap();
+  assert_eq!(signed_txn.encode().unwrap(), expected_signed_bytes);
+  assert_eq!(signed_txn.txn_id, "254L4I5PMJ5BKKNNSGAFGAJNYAYG7QCD5R2PCOCBU5IWMU7IOD2Q");
+}
+
+#[test]
+fn test_nonparticipating_key_reg_transaction_fails_with_keys() {
+  let actual_error = Transaction::from_input(KeyRegTransactionInput {
+    nonparticipating: true,
+    ..offline_key_reg_input(
+      Some("Kv7QI7chi1y6axoy+t7wzAVpePqRq/rkjzWh/RMYyLo=".into()),
+      Some("bPgrv4YogPcdaUAxrt1QysYZTVyRAuUMD4zQmCu9llc=".into()),
+    )
+  }).err().unwrap();
+
+  match actual_error {
+    AlgorandSdkError::InvalidKeyReg(_) => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
 #[test]
 fn test_asset_cfg_transaction_works() {
   let address: String = "BH55E5RMBD4GYWXGX5W5PJ5JAHPGM5OXKDQH5DC4O2MGI7NW4H6VOE4CP4".into();
@@ -79,12 +322,12 @@ fn test_asset_cfg_transaction_works() {
 
   let txn = Transaction::from_input(AssetConfigTransactionInput {
     from: address.clone(),
-    fee: 10,
+    fee: MicroAlgos(10),
     first_round: 322575,
     last_round: 323575,
     note: None,
     genesis_id: String::new(),
-    genesis_hash: String::from("SGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiI="),
+    genesis_hash: "SGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiI=".into(),
     creator: address.clone(),
     index: 1234,
     manager: Some(address.clone()),
@@ -100,3 +343,1185 @@ fn test_asset_cfg_transaction_works() {
 
   assert_eq!(actual_signed_bytes, expected_signed_bytes);
 }
+
+fn payment_input(from: &Account, to: &Account, amount: u64) -> PaymentTransactionInput {
+  PaymentTransactionInput {
+    from: from.address.to_string(),
+    to: to.address.to_string(),
+    fee: MicroAlgos(1000),
+    amount: MicroAlgos(amount),
+    first_round: 12466,
+    last_round: 13466,
+    note: None,
+    close_remainder_to: None,
+    allow_self_close: false,
+    genesis_id: "devnet-v33.0".into(),
+    genesis_hash: "JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI=".into(),
+    is_flat_fee: true,
+  }
+}
+
+#[test]
+fn test_atomic_transaction_group_works() {
+  let account_a = Account::generate();
+  let account_b = Account::generate();
+
+  let mut group = AtomicTransactionGroup::new();
+  group.add(Transaction::from_input(payment_input(&account_a, &account_b, 5000)).unwrap()).unwrap()
+    .add(Transaction::from_input(payment_input(&account_b, &account_a, 3000)).unwrap()).unwrap();
+
+  let txns = group.build().unwrap();
+  assert_eq!(txns.len(), 2);
+  assert!(txns[0].header.group.is_some());
+  assert_eq!(txns[0].header.group, txns[1].header.group);
+
+  let (signed_txns, bytes) = group.sign(&[&account_a, &account_b]).unwrap();
+  let group_id = txns[0].header.group.unwrap().to_vec();
+  assert_eq!(signed_txns[0].transaction.group.as_ref().unwrap().to_vec(), group_id);
+  assert_eq!(signed_txns[1].transaction.group.as_ref().unwrap().to_vec(), group_id);
+
+  let mut expected_bytes = signed_txns[0].encode().unwrap();
+  expected_bytes.extend(signed_txns[1].encode().unwrap());
+  assert_eq!(bytes, expected_bytes);
+
+  // signers must match transactions
+  match group.sign(&[&account_a]).unwrap_err() {
+    AlgorandSdkError::MismatchedGroupSigners { expected: 2, got: 1 } => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+
+  match AtomicTransactionGroup::new().build().unwrap_err() {
+    AlgorandSdkError::EmptyTransactionGroup() => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+  match assign_group_id(txns).unwrap_err() {
+    AlgorandSdkError::GroupIdAlreadyAssigned() => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_group_fails_with_mismatched_genesis() {
+  let account_a = Account::generate();
+  let account_b = Account::generate();
+  let mainnet_input = PaymentTransactionInput {
+    genesis_id: "mainnet-v1.0".into(),
+    genesis_hash: "wGHE2Pwdvd7S12BL5FaOP20EGYesN73ktiC1qzkkit8=".into(),
+    ..payment_input(&account_b, &account_a, 3000)
+  };
+
+  let mut group = AtomicTransactionGroup::new();
+  group.add(Transaction::from_input(payment_input(&account_a, &account_b, 5000)).unwrap()).unwrap()
+    .add(Transaction::from_input(mainnet_input).unwrap()).unwrap();
+
+  let actual_error = group.build().unwrap_err();
+  match actual_error {
+    AlgorandSdkError::MismatchedGroupGenesis { index: 1, expected, got } => {
+      assert_eq!(expected, "devnet-v33.0 (JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI=)");
+      assert_eq!(got, "mainnet-v1.0 (wGHE2Pwdvd7S12BL5FaOP20EGYesN73ktiC1qzkkit8=)");
+    },
+    other => panic!("Unexpected error {:?}", other),
+  }
+
+  // the same genesis id with another genesis hash is rejected too
+  let other_hash_input = PaymentTransactionInput {
+    genesis_hash: "wGHE2Pwdvd7S12BL5FaOP20EGYesN73ktiC1qzkkit8=".into(),
+    ..payment_input(&account_b, &account_a, 3000)
+  };
+  let txns = vec![
+    Transaction::from_input(payment_input(&account_a, &account_b, 5000)).unwrap(),
+    Transaction::from_input(other_hash_input).unwrap(),
+  ];
+  let actual_error = assign_group_id(txns).unwrap_err();
+  match actual_error {
+    AlgorandSdkError::MismatchedGroupGenesis { index: 1, .. } => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+fn asset_transfer_input(from: &Account, to: &Account, asset_id: u64, amount: u64) -> AssetTransferTransactionInput {
+  AssetTransferTransactionInput {
+    from: from.address.to_string(),
+    fee: MicroAlgos(1000),
+    first_round: 12466,
+    last_round: 13466,
+    note: None,
+    genesis_id: "devnet-v33.0".into(),
+    genesis_hash: "JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI=".into(),
+    is_flat_fee: true,
+    asset_id,
+    to: to.address.to_string(),
+    amount,
+    revocation_target: None,
+    close_assets_to: None,
+  }
+}
+
+#[test]
+fn test_atomic_swap_works() {
+  let account_a = Account::generate();
+  let account_b = Account::generate();
+  let payment = Transaction::from_input(payment_input(&account_a, &account_b, 5000)).unwrap();
+  let asset_transfer = Transaction::from_input(asset_transfer_input(&account_b, &account_a, 1234, 10)).unwrap();
+
+  let txns = atomic_swap(payment.clone(), asset_transfer.clone()).unwrap();
+  assert_eq!(txns.len(), 2);
+  assert_eq!(txns[0].tx_type, TxType::Payment);
+  assert_eq!(txns[1].tx_type, TxType::AssetTransfer);
+  let group_id = txns[0].header.group.unwrap();
+  assert_eq!(txns[1].header.group, Some(group_id));
+
+  // each sender signs independently and the group id is preserved
+  let signed_payment = txns[0].sign(&account_a).unwrap();
+  let signed_asset_transfer = txns[1].sign(&account_b).unwrap();
+  assert_eq!(signed_payment.transaction.group.as_ref().unwrap().to_vec(), group_id.to_vec());
+  assert_eq!(signed_asset_transfer.transaction.group.as_ref().unwrap().to_vec(), group_id.to_vec());
+
+  let actual_error = atomic_swap(asset_transfer, payment).unwrap_err();
+  match actual_error {
+    AlgorandSdkError::InvalidAtomicSwap { expected, got } => assert_eq!((expected.as_str(), got.as_str()), ("pay", "axfer")),
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_payment_group_works() {
+  let sender = Account::generate();
+  let receivers = [Account::generate(), Account::generate(), Account::generate()];
+  let inputs = receivers.iter()
+    .map(|receiver| payment_input(&sender, receiver, 1000))
+    .collect();
+
+  let txns = Transaction::payment_group(inputs).unwrap();
+
+  assert_eq!(txns.len(), 3);
+  assert!(txns[0].header.group.is_some());
+  assert_eq!(txns[0].header.group, txns[1].header.group);
+  assert_eq!(txns[1].header.group, txns[2].header.group);
+}
+
+#[test]
+fn test_payment_group_fails_if_too_large() {
+  let sender = Account::generate();
+  let receiver = Account::generate();
+  let inputs = (0..17)
+    .map(|_| payment_input(&sender, &receiver, 1000))
+    .collect();
+
+  let actual_error = Transaction::payment_group(inputs).unwrap_err();
+  match actual_error {
+    AlgorandSdkError::TooManyTransactionsInGroup { max: 16, got: 17 } => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_payment_rejects_close_remainder_to_sender() {
+  let account = Account::generate();
+  let receiver = Account::generate();
+  let mut input = payment_input(&account, &receiver, 1000);
+  input.close_remainder_to = Some(account.address.to_string());
+
+  match Transaction::from_input(input).unwrap_err() {
+    AlgorandSdkError::CloseToSender(sender) => assert_eq!(sender, account.address.to_string()),
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_payment_allows_close_remainder_to_sender_when_opted_out() {
+  let account = Account::generate();
+  let receiver = Account::generate();
+  let mut input = payment_input(&account, &receiver, 1000);
+  input.close_remainder_to = Some(account.address.to_string());
+  input.allow_self_close = true;
+
+  let txn = Transaction::from_input(input).unwrap();
+  assert_eq!(txn.payment_params.unwrap().close_remainder_to.unwrap(), account.address.into_bytes());
+}
+
+#[test]
+fn test_signing_ungrouped_group_member_fails() {
+  let account_a = Account::generate();
+  let account_b = Account::generate();
+
+  let mut group = AtomicTransactionGroup::new();
+  group.add(Transaction::from_input(payment_input(&account_a, &account_b, 5000)).unwrap()).unwrap()
+    .add(Transaction::from_input(payment_input(&account_b, &account_a, 3000)).unwrap()).unwrap();
+
+  let ungrouped_txn = group.transactions()[0].clone();
+  match ungrouped_txn.sign(&account_a).err().unwrap() {
+    AlgorandSdkError::MissingGroupId() => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+
+  let grouped_txn = group.build().unwrap().remove(0);
+  assert!(grouped_txn.sign(&account_a).is_ok());
+}
+
+#[test]
+fn test_estimate_fee_of_group_member_before_build() {
+  let account_a = Account::generate();
+  let account_b = Account::generate();
+
+  let mut group = AtomicTransactionGroup::new();
+  group.add(Transaction::from_input(payment_input(&account_a, &account_b, 5000)).unwrap()).unwrap()
+    .add(Transaction::from_input(payment_input(&account_b, &account_a, 3000)).unwrap()).unwrap();
+
+  // the estimate accounts for the group id assigned on build
+  let estimated_fee = group.transactions()[0].estimate_fee(10, 1000).unwrap();
+  let grouped_txn = group.build().unwrap().remove(0);
+  assert!(estimated_fee > 1000);
+  assert_eq!(grouped_txn.estimate_fee(10, 1000).unwrap(), estimated_fee);
+}
+
+#[test]
+fn test_transaction_id_matches_signed_transaction_id() {
+  let account = Account::generate();
+  let receiver = Account::generate();
+  let txn = Transaction::from_input(payment_input(&account, &receiver, 1000)).unwrap();
+
+  let signed_txn = txn.sign(&account).unwrap();
+
+  assert_eq!(txn.id().unwrap(), signed_txn.txn_id);
+}
+
+#[test]
+fn test_asset_cfg_transaction_clears_role_with_zero_address() {
+  let address: String = "BH55E5RMBD4GYWXGX5W5PJ5JAHPGM5OXKDQH5DC4O2MGI7NW4H6VOE4CP4".into();
+  let zero_address: String = "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAY5HFKQ".into();
+
+  let txn = Transaction::from_input(AssetConfigTransactionInput {
+    from: address.clone(),
+    fee: MicroAlgos(10),
+    first_round: 322575,
+    last_round: 323575,
+    note: None,
+    genesis_id: String::new(),
+    genesis_hash: "SGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiI=".into(),
+    creator: address.clone(),
+    index: 1234,
+    manager: Some(zero_address),
+    reserve: Some(address.clone()),
+    freeze: None,
+    clawback: None,
+    is_flat_fee: false,
+  }).unwrap();
+
+  let asset_params = txn.to_raw().asset_params.unwrap();
+  assert_eq!(asset_params.manager.unwrap().to_vec(), vec![0; 32]);
+  assert!(asset_params.reserve.is_some());
+  assert!(asset_params.freeze.is_none());
+}
+
+#[test]
+fn test_asset_cfg_transaction_decodes_from_msgpack() {
+  let address: String = "BH55E5RMBD4GYWXGX5W5PJ5JAHPGM5OXKDQH5DC4O2MGI7NW4H6VOE4CP4".into();
+  let reserve = "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU";
+
+  let txn = Transaction::from_input(AssetConfigTransactionInput {
+    from: address.clone(),
+    fee: MicroAlgos(10),
+    first_round: 322575,
+    last_round: 323575,
+    note: None,
+    genesis_id: String::new(),
+    genesis_hash: "SGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiI=".into(),
+    creator: address.clone(),
+    index: 1234,
+    manager: Some(address.clone()),
+    reserve: Some(reserve.into()),
+    freeze: None,
+    clawback: None,
+    is_flat_fee: false,
+  }).unwrap();
+
+  let decoded_txn = Transaction::from_msgpack_base64(&base64_encode(txn.to_unsigned_bytes().unwrap())).unwrap();
+  assert_eq!(decoded_txn.tx_type.to_string(), "acfg");
+  assert_eq!(decoded_txn.id().unwrap(), txn.id().unwrap());
+  let asset_config = decoded_txn.asset_config_params.unwrap();
+  assert_eq!(asset_config.asset_id.index, 1234);
+  assert_eq!(asset_config.asset_id.creator, Address::from_string(&address).unwrap().to_vec());
+  let asset_params = asset_config.asset_params.unwrap();
+  assert_eq!(asset_params.manager.unwrap().to_vec(), Address::from_string(&address).unwrap().to_vec());
+  assert_eq!(asset_params.reserve.unwrap().to_vec(), Address::from_string(reserve).unwrap().to_vec());
+  assert!(asset_params.freeze.is_none());
+  assert!(asset_params.clawback.is_none());
+
+  // an asset creation has no caid, it decodes to a zero asset id and encodes back without one
+  let mut creation_raw = txn.to_raw();
+  creation_raw.asset_id = None;
+  creation_raw.asset_params = Some(AssetParamsBuilder::new().total(1000).unit_name("TOK").manager(&address).build().unwrap());
+  let creation_bytes = rmp_encode(&creation_raw).unwrap();
+
+  let creation_txn = Transaction::from_msgpack_base64(&base64_encode(&creation_bytes)).unwrap();
+  let asset_config = creation_txn.asset_config_params.as_ref().unwrap();
+  assert_eq!(asset_config.asset_id.index, 0);
+  assert_eq!(asset_config.asset_params.as_ref().unwrap().total, Some(1000));
+  assert_eq!(rmp_encode(&creation_txn.to_raw()).unwrap(), creation_bytes);
+}
+
+#[test]
+fn test_asset_opt_in_transaction_works() {
+  let address = "BH55E5RMBD4GYWXGX5W5PJ5JAHPGM5OXKDQH5DC4O2MGI7NW4H6VOE4CP4";
+  let mnemonic = "advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor";
+  let golden = "gqNzaWfEQP5y77j3FdgkKopoUrBBnDMc6X+VEhqKdDkmny3oESGOZFnPtZNt4qIbvdHKnIlC1o7A4We7JfF/CX2J8UjJNQyjdHhuiKRhcmN2xCAJ+9J2LAj4bFrmv23Xp6kB3mZ111Dgfoxcdphkfbbh/aNmZWXNA+iiZnbOAATsD6JnaMQgSGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiKibHbOAATv96NzbmTEIAn70nYsCPhsWua/bdenqQHeZnXXUOB+jFx2mGR9tuH9pHR5cGWlYXhmZXKkeGFpZM0E0g==";
+  let expected_signed_bytes = base64_decode(golden).unwrap();
+  let account = Account::from_mnemonic(mnemonic).unwrap();
+
+  let txn = Transaction::from_input(AssetTransferTransactionInput::opt_in(address, 1234, SuggestedParams {
+    fee: MicroAlgos(1000),
+    first_round: 322575,
+    last_round: 323575,
+    genesis_id: "".into(),
+    genesis_hash: "SGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiI=".into(),
+    is_flat_fee: true,
+    min_fee: MINIMUM_TX_FEE,
+  })).unwrap();
+
+  let raw_txn = txn.to_raw();
+  assert_eq!(raw_txn.arcv.as_ref(), Some(&raw_txn.sender));
+  assert!(raw_txn.aamt.is_none());
+
+  let signed_txn = txn.sign(&account).unwrap();
+  assert_eq!(signed_txn.encode().unwrap(), expected_signed_bytes);
+  assert_eq!(signed_txn.txn_id, "4L7DUIT2MTAQ4QFCN4HZACRMZGAYVWCAE4Y4Z56LOCMU5URF6XIQ");
+}
+
+#[test]
+fn test_close_account_transaction() {
+  let account = Account::generate();
+  let close_to = Account::generate();
+  let params = SuggestedParams {
+    fee: MicroAlgos(1000),
+    first_round: 322575,
+    last_round: 323575,
+    genesis_id: "".into(),
+    genesis_hash: "SGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiI=".into(),
+    is_flat_fee: true,
+    min_fee: MINIMUM_TX_FEE,
+  };
+
+  let from = account.address.to_string();
+  let txn = Transaction::from_input(PaymentTransactionInput::close_account(&from, &close_to.address.to_string(), params.clone())).unwrap();
+  let raw_txn = txn.to_raw();
+  assert!(raw_txn.amount.is_none());
+  assert_eq!(raw_txn.close_remainder_to.unwrap().as_ref(), close_to.address.as_bytes());
+  assert_eq!(raw_txn.receiver.unwrap().as_ref(), close_to.address.as_bytes());
+
+  let actual_error = Transaction::from_input(PaymentTransactionInput::close_account(&from, &from, params)).unwrap_err();
+  match actual_error {
+    AlgorandSdkError::CloseToSender(_) => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_asset_opt_out_transaction() {
+  let account = Account::generate();
+  let close_to = Account::generate();
+  let params = SuggestedParams {
+    fee: MicroAlgos(1000),
+    first_round: 322575,
+    last_round: 323575,
+    genesis_id: "".into(),
+    genesis_hash: "SGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiI=".into(),
+    is_flat_fee: true,
+    min_fee: MINIMUM_TX_FEE,
+  };
+
+  let address = account.address.to_string();
+  let txn = Transaction::from_input(AssetTransferTransactionInput::opt_out(&address, 1234, &close_to.address.to_string(), params.clone())).unwrap();
+  let raw_txn = txn.to_raw();
+  assert_eq!(raw_txn.aclose.unwrap().as_ref(), close_to.address.as_bytes());
+  assert_eq!(raw_txn.arcv.unwrap().as_ref(), close_to.address.as_bytes());
+  assert_eq!(raw_txn.xaid, Some(1234));
+  assert!(raw_txn.aamt.is_none());
+
+  let actual_error = Transaction::from_input(AssetTransferTransactionInput::opt_out(&address, 1234, "MFRGG", params)).unwrap_err();
+  match actual_error {
+    AlgorandSdkError::WrongAddressLength(_, _) => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+fn assert_raw_round_trip(txn: Transaction) {
+  let rebuilt_txn = Transaction::from_raw(txn.to_raw()).unwrap();
+  assert_eq!(rebuilt_txn.to_raw_bytes().unwrap(), txn.to_raw_bytes().unwrap());
+  assert_eq!(rebuilt_txn.tx_type.to_string(), txn.tx_type.to_string());
+}
+
+#[test]
+fn test_transaction_from_raw_round_trips() {
+  let account = Account::generate();
+  let receiver = Account::generate();
+  let mut payment = payment_input(&account, &receiver, 1000);
+  payment.note = Some(vec![1, 2, 3]);
+  payment.close_remainder_to = Some(Account::generate().address.to_string());
+  let mut payment_txn = Transaction::from_input(payment).unwrap();
+  payment_txn.header.group = Some([9; 32]);
+  assert_raw_round_trip(payment_txn);
+
+  assert_raw_round_trip(Transaction::from_input(offline_key_reg_input(None, None)).unwrap());
+  assert_raw_round_trip(Transaction::from_input(KeyRegTransactionInput {
+    nonparticipating: true,
+    ..offline_key_reg_input(None, None)
+  }).unwrap());
+  assert_raw_round_trip(Transaction::from_input(KeyRegTransactionInput {
+    vote_first: 10000,
+    vote_last: 10111,
+    vote_key_dilution: 11,
+    state_proof_pk: Some("mYR0GVEObMTSNdsKM6RwYywHYPqVDqg3E4JFzxZOreH9NU8B+tKzUanyY8AQ144hETgSMX7fXWwjBdHz6AWk9w==".into()),
+    ..offline_key_reg_input(
+      Some("Kv7QI7chi1y6axoy+t7wzAVpePqRq/rkjzWh/RMYyLo=".into()),
+      Some("bPgrv4YogPcdaUAxrt1QysYZTVyRAuUMD4zQmCu9llc=".into()),
+    )
+  }).unwrap());
+
+  let address = "BH55E5RMBD4GYWXGX5W5PJ5JAHPGM5OXKDQH5DC4O2MGI7NW4H6VOE4CP4";
+  assert_raw_round_trip(Transaction::from_input(AssetConfigTransactionInput {
+    from: address.into(),
+    fee: MicroAlgos(10),
+    first_round: 322575,
+    last_round: 323575,
+    note: None,
+    genesis_id: String::new(),
+    genesis_hash: "SGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiI=".into(),
+    creator: address.into(),
+    index: 1234,
+    manager: Some(address.into()),
+    reserve: None,
+    freeze: None,
+    clawback: None,
+    is_flat_fee: false,
+  }).unwrap());
+  assert_raw_round_trip(Transaction::from_input(AssetTransferTransactionInput::opt_in(address, 1234, SuggestedParams {
+    fee: MicroAlgos(1000),
+    first_round: 322575,
+    last_round: 323575,
+    genesis_id: "".into(),
+    genesis_hash: "SGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiI=".into(),
+    is_flat_fee: true,
+    min_fee: MINIMUM_TX_FEE,
+  })).unwrap());
+}
+
+#[test]
+fn test_transaction_from_raw_reads_absent_addresses_as_zero() {
+  let account = Account::generate();
+  let txn = Transaction::from_input(payment_input(&account, &Account::generate(), 0)).unwrap();
+  let mut raw_txn = txn.to_raw();
+  raw_txn.receiver = None;
+
+  let rebuilt_txn = Transaction::from_raw(raw_txn).unwrap();
+  let payment_params = rebuilt_txn.payment_params.unwrap();
+  assert_eq!(payment_params.receiver, [0; 32]);
+  assert!(payment_params.close_remainder_to.is_none());
+
+  let mut raw_txn = txn.to_raw();
+  raw_txn.sender = ByteBuf::from(vec![1; 31]);
+  let actual_error = Transaction::from_raw(raw_txn).unwrap_err();
+  match actual_error {
+    AlgorandSdkError::InvalidEncoding(field, _) => assert_eq!(field, "snd"),
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_transaction_from_msgpack_base64() {
+  let unsigned_txn = "i6NhbXTNA+ilY2xvc2XEIEDpNJKIJWTLzpxZpptnVCaJ6aHDoqnqW2Wm6KRCH/xXo2ZlZc0EmKJmds0wsqNnZW6sZGV2bmV0LXYzMy4womdoxCAmCyAJoJOohot5WHIvpeVG7eftF+TYXEx4r7BFJpDt0qJsds00mqRub3RlxAjqABVHQ2y/lqNyY3bEIHts4k/rW6zAsWTinCIsV/X2PcOH1DkEglhBHF/hD3wCo3NuZMQg5/D4TQaBHfnzHI2HixFV9GcdUaGFwgCQhmf0SVhwaKGkdHlwZaNwYXk=";
+
+  let txn = Transaction::from_msgpack_base64(unsigned_txn).unwrap();
+  assert_eq!(txn.id().unwrap(), "5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ");
+  assert_eq!(txn.header.fee, MicroAlgos(1176));
+  let payment_params = txn.payment_params.as_ref().unwrap();
+  assert_eq!(payment_params.amount, MicroAlgos(1000));
+  assert_eq!(txn.header.note.as_ref().unwrap(), &base64_decode("6gAVR0Nsv5Y=").unwrap());
+
+  // the tagged bytes to sign decode to the same transaction
+  let tagged_txn = Transaction::from_msgpack_base64(&base64_encode(txn.to_unsigned_bytes().unwrap())).unwrap();
+  assert!(tagged_txn == txn);
+
+  assert!(Transaction::from_msgpack_base64("not base64!").is_err());
+  assert!(Transaction::from_msgpack_base64(&base64_encode(b"TXnot msgpack")).is_err());
+}
+
+#[test]
+fn test_signed_transaction_verify() {
+  let account = Account::generate();
+  let receiver = Account::generate();
+  let mut signed_txn = Transaction::from_input(payment_input(&account, &receiver, 1000)).unwrap().sign(&account).unwrap();
+  assert!(signed_txn.verify().unwrap());
+
+  signed_txn.transaction.amount = Some(MicroAlgos(1_000_000));
+  assert!(!signed_txn.verify().unwrap());
+
+  signed_txn.signature = None;
+  assert!(!signed_txn.verify().unwrap());
+}
+
+#[test]
+fn test_signed_transaction_to_transaction() {
+  let account = Account::generate();
+  let txn = Transaction::from_input(payment_input(&account, &Account::generate(), 1000)).unwrap();
+  let signed_txn = SignedTransaction::decode_and_verify(&txn.sign(&account).unwrap().encode().unwrap()).unwrap();
+
+  let decoded_txn = signed_txn.to_transaction().unwrap();
+  assert!(decoded_txn == txn);
+  assert_eq!(decoded_txn.id().unwrap(), signed_txn.txn_id);
+}
+
+#[test]
+fn test_signed_payment_to_unsigned() {
+  let account = Account::generate();
+  let receiver = Account::generate();
+  let signed_txn = Transaction::from_input(payment_input(&account, &receiver, 1000)).unwrap().sign(&account).unwrap();
+  let decoded_signed_txn: SignedTransaction = rmp_decode(&signed_txn.encode().unwrap()).unwrap();
+
+  let txn = decoded_signed_txn.to_unsigned().unwrap();
+  assert_eq!(&txn.header.sender[..], account.address.as_bytes());
+  assert_eq!(txn.header.fee, MicroAlgos(1000));
+  let payment_params = txn.payment_params.as_ref().unwrap();
+  assert_eq!(&payment_params.receiver[..], receiver.address.as_bytes());
+  assert_eq!(payment_params.amount, MicroAlgos(1000));
+  assert_eq!(txn.sign(&account).unwrap().encode().unwrap(), signed_txn.encode().unwrap());
+}
+
+#[test]
+fn test_partially_signed_multisig_to_unsigned() {
+  // 1 of 2 signatures, which doesn't meet the threshold
+  let signed_txn = multisig_signed_payment(&[true, false]);
+  let decoded_signed_txn: SignedTransaction = rmp_decode(&signed_txn.encode().unwrap()).unwrap();
+
+  let txn = decoded_signed_txn.to_unsigned().unwrap();
+  assert_eq!(txn.id().unwrap(), signed_txn.txn_id);
+  assert_eq!(txn.payment_params.unwrap().amount, MicroAlgos(1000));
+  assert_eq!(&txn.header.sender[..], signed_txn.transaction.sender.as_ref());
+}
+
+#[test]
+fn test_sign_with_auth_address_for_rekeyed_account() {
+  let mnemonic = "advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor";
+  let golden = "g6RzZ25yxCDn8PhNBoEd+fMcjYeLEVX0Zx1RoYXCAJCGZ/RJWHBooaNzaWfEQC9xvs86EFZ5KB56y27bOplDOVnemj6XHdjIhzSTRryjYnkPIX9anykayPtTRcYp49bB2ayF3w7j2MycEVG6nA2jdHhuiaNhbXTNA+ijZmVlzQPoomZ2zTCyo2dlbqxkZXZuZXQtdjMzLjCiZ2jEICYLIAmgk6iGi3lYci+l5Ubt5+0X5NhcTHivsEUmkO3Somx2zTSao3JjdsQgQOk0koglZMvOnFmmm2dUJonpocOiqepbZabopEIf/Fejc25kxCB7bOJP61uswLFk4pwiLFf19j3Dh9Q5BIJYQRxf4Q98AqR0eXBlo3BheQ==";
+  let signer = Account::from_mnemonic(mnemonic).unwrap();
+
+  let txn = Transaction::from_input(PaymentTransactionInput {
+    from: "PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI".into(),
+    to: "IDUTJEUIEVSMXTU4LGTJWZ2UE2E6TIODUKU6UW3FU3UKIQQ77RLUBBBFLA".into(),
+    fee: MicroAlgos(1000),
+    amount: MicroAlgos(1000),
+    first_round: 12466,
+    last_round: 13466,
+    note: None,
+    close_remainder_to: None,
+    allow_self_close: false,
+    genesis_id: "devnet-v33.0".into(),
+    genesis_hash: "JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI=".into(),
+    is_flat_fee: true,
+  }).unwrap();
+
+  let signed_txn = txn.sign_with_auth(&signer, &signer.address).unwrap();
+  assert_eq!(signed_txn.auth_address.as_ref().unwrap().to_vec(), signer.address.to_vec());
+  assert_eq!(signed_txn.encode().unwrap(), base64_decode(golden).unwrap());
+  assert_eq!(signed_txn.txn_id, "L4YDNEHFT3ZAHJ3HIY75QHE44BGOUR3JVKDBUWYU3YPVSPNLMGVQ");
+
+  // the signature is checked against the auth address instead of the sender
+  SignedTransaction::decode_and_verify(&signed_txn.encode().unwrap()).unwrap();
+  assert!(signed_txn.verify().unwrap());
+  let mut without_auth_address = txn.sign_with_auth(&signer, &signer.address).unwrap();
+  without_auth_address.auth_address = None;
+  assert!(!without_auth_address.verify().unwrap());
+
+  let actual_error = txn.sign_with_auth(&Account::generate(), &signer.address).unwrap_err();
+  match actual_error {
+    AlgorandSdkError::MismatchedAuthAddress(_, _) => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_sign_with_auth_address_of_sender_omits_signer() {
+  let account = Account::generate();
+  let txn = Transaction::from_input(payment_input(&account, &Account::generate(), 1000)).unwrap();
+
+  let signed_txn = txn.sign_with_auth(&account, &account.address).unwrap();
+  assert!(signed_txn.auth_address.is_none());
+  assert_eq!(signed_txn.encode().unwrap(), txn.sign(&account).unwrap().encode().unwrap());
+}
+
+#[test]
+fn test_transaction_fails_with_invalid_genesis_hash() {
+  let account = Account::generate();
+  let receiver = Account::generate();
+
+  let mut input = payment_input(&account, &receiver, 1000);
+  input.genesis_hash = String::new().into();
+  let actual_error = Transaction::from_input(input).unwrap_err();
+  match actual_error {
+    AlgorandSdkError::MissingGenesisHash() => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+
+  let mut input = payment_input(&account, &receiver, 1000);
+  input.genesis_hash = "SGO1GKSzyE7IEPItTxCByw9x8Fmn".into();
+  let actual_error = Transaction::from_input(input).unwrap_err();
+  match actual_error {
+    AlgorandSdkError::InvalidGenesisHashLength { expected: 32, got: 21 } => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_transaction_accepts_genesis_hash_bytes() {
+  let account = Account::generate();
+  let receiver = Account::generate();
+  let genesis_hash = "JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI=";
+
+  let base64_txn = Transaction::from_input(payment_input(&account, &receiver, 1000)).unwrap();
+
+  let mut input = payment_input(&account, &receiver, 1000);
+  let mut genesis_hash_bytes = [0u8; 32];
+  genesis_hash_bytes.copy_from_slice(&base64_decode(genesis_hash).unwrap());
+  input.genesis_hash = GenesisHash::Bytes(genesis_hash_bytes);
+  let bytes_txn = Transaction::from_input(input).unwrap();
+
+  assert_eq!(bytes_txn.header.genesis_hash, genesis_hash_bytes);
+  assert_eq!(bytes_txn.to_raw_bytes().unwrap(), base64_txn.to_raw_bytes().unwrap());
+}
+
+#[test]
+fn test_transaction_accepts_genesis_hash_vec() {
+  let account = Account::generate();
+  let receiver = Account::generate();
+  let genesis_hash: Vec<u8> = base64_decode("JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI=").unwrap();
+
+  let base64_txn = Transaction::from_input(payment_input(&account, &receiver, 1000)).unwrap();
+
+  let mut input = payment_input(&account, &receiver, 1000);
+  input.genesis_hash = GenesisHash::from_bytes(&genesis_hash).unwrap();
+  let bytes_txn = Transaction::from_input(input).unwrap();
+  assert_eq!(bytes_txn.header.genesis_hash.to_vec(), genesis_hash);
+  assert_eq!(bytes_txn.to_raw_bytes().unwrap(), base64_txn.to_raw_bytes().unwrap());
+
+  let actual_error = GenesisHash::from_bytes(&genesis_hash[..31]).unwrap_err();
+  match actual_error {
+    AlgorandSdkError::InvalidGenesisHashLength { expected: 32, got: 31 } => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+  assert!(GenesisHash::from_bytes(&[]).is_err());
+}
+
+#[test]
+fn test_transactions_from_identical_inputs_are_equal() {
+  let account = Account::generate();
+  let receiver = Account::generate();
+
+  let txn = Transaction::from_input(payment_input(&account, &receiver, 1000)).unwrap();
+  assert_eq!(txn, Transaction::from_input(payment_input(&account, &receiver, 1000)).unwrap());
+  assert_ne!(txn, Transaction::from_input(payment_input(&account, &receiver, 2000)).unwrap());
+
+  let signed_txn = txn.sign(&account).unwrap();
+  assert!(format!("{:?}", signed_txn).contains(&signed_txn.txn_id));
+}
+
+#[test]
+fn test_raw_transactions_from_identical_inputs_are_equal() {
+  let account = Account::generate();
+  let receiver = Account::generate();
+
+  let raw_txn = Transaction::from_input(payment_input(&account, &receiver, 1000)).unwrap().to_raw();
+  assert_eq!(raw_txn, Transaction::from_input(payment_input(&account, &receiver, 1000)).unwrap().to_raw());
+  assert_eq!(raw_txn, raw_txn.clone());
+  assert_ne!(raw_txn, Transaction::from_input(payment_input(&account, &receiver, 2000)).unwrap().to_raw());
+}
+
+#[test]
+fn test_total_outflow() {
+  let account = Account::generate();
+  let receiver = Account::generate();
+
+  let txn = Transaction::from_input(payment_input(&account, &receiver, 5000)).unwrap();
+  assert_eq!(txn.total_outflow().unwrap(), MicroAlgos(6000));
+
+  let txn = Transaction::from_input(payment_input(&account, &receiver, u64::MAX - 10)).unwrap();
+  let actual_error = txn.total_outflow().unwrap_err();
+  match actual_error {
+    AlgorandSdkError::AmountOverflow() => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_set_validity() {
+  let account = Account::generate();
+  let receiver = Account::generate();
+
+  let mut input = payment_input(&account, &receiver, 1000);
+  input.set_validity(Round(20000), 500).unwrap();
+  assert_eq!(input.first_round, 20000);
+  assert_eq!(input.last_round, 20500);
+
+  let actual_error = input.set_validity(Round(20000), 1001).unwrap_err();
+  match actual_error {
+    AlgorandSdkError::InvalidValidityWindow { max: 1000, got: 1001 } => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+
+  let actual_error = input.set_validity(Round(u64::MAX - 10), 1000).unwrap_err();
+  match actual_error {
+    AlgorandSdkError::RoundOverflow() => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+  // a failed call leaves the rounds unchanged
+  assert_eq!(input.first_round, 20000);
+  assert_eq!(input.last_round, 20500);
+}
+
+#[test]
+fn test_transaction_fails_with_note_too_long() {
+  let account = Account::generate();
+  let receiver = Account::generate();
+
+  let mut input = payment_input(&account, &receiver, 1000);
+  input.note = Some(vec![1; 1024]);
+  assert!(Transaction::from_input(input).is_ok());
+
+  let mut input = payment_input(&account, &receiver, 1000);
+  input.note = Some(vec![1; 1025]);
+  let actual_error = Transaction::from_input(input).unwrap_err();
+  match actual_error {
+    AlgorandSdkError::NoteTooLong { max: 1024, got: 1025 } => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_with_note_string() {
+  let account = Account::generate();
+  let receiver = Account::generate();
+
+  let input = payment_input(&account, &receiver, 1000).with_note_string("invoice #123 ✓").unwrap();
+  let txn = Transaction::from_input(input).unwrap();
+  let decoded_txn = Transaction::from_msgpack_base64(&base64_encode(txn.to_unsigned_bytes().unwrap())).unwrap();
+  assert_eq!(decoded_txn.header.note.unwrap(), "invoice #123 ✓".as_bytes());
+
+  // the limit is in bytes, so multi byte characters count more than once
+  let long_note = "✓".repeat(342);
+  let actual_error = payment_input(&account, &receiver, 1000).with_note_string(&long_note).err().unwrap();
+  match actual_error {
+    AlgorandSdkError::NoteTooLong { max: 1024, got: 1026 } => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_transaction_fails_with_fee_overflow() {
+  let account = Account::generate();
+  let receiver = Account::generate();
+
+  let mut input = payment_input(&account, &receiver, 1000);
+  input.fee = MicroAlgos(u64::MAX);
+  input.is_flat_fee = false;
+  let actual_error = Transaction::from_input(input).unwrap_err();
+  match actual_error {
+    AlgorandSdkError::FeeOverflow() => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_signed_transaction_to_json() {
+  let account = Account::generate();
+  let receiver = Account::generate();
+  let signed_txn = Transaction::from_input(payment_input(&account, &receiver, 1000)).unwrap()
+    .sign(&account).unwrap();
+
+  let json = signed_txn.to_json().unwrap();
+
+  assert!(json.contains(&format!(r#""txid":"{}""#, signed_txn.txn_id)));
+  assert!(json.contains(&format!(r#""snd":"{}""#, base64_encode(account.address.as_bytes()))));
+}
+
+#[test]
+fn test_peek_tx_type() {
+  let golden = "gqNzaWfEQPhUAZ3xkDDcc8FvOVo6UinzmKBCqs0woYSfodlmBMfQvGbeUx3Srxy3dyJDzv7rLm26BRv9FnL2/AuT7NYfiAWjdHhui6NhbXTNA+ilY2xvc2XEIEDpNJKIJWTLzpxZpptnVCaJ6aHDoqnqW2Wm6KRCH/xXo2ZlZc0EmKJmds0wsqNnZW6sZGV2bmV0LXYzMy4womdoxCAmCyAJoJOohot5WHIvpeVG7eftF+TYXEx4r7BFJpDt0qJsds00mqRub3RlxAjqABVHQ2y/lqNyY3bEIHts4k/rW6zAsWTinCIsV/X2PcOH1DkEglhBHF/hD3wCo3NuZMQg5/D4TQaBHfnzHI2HixFV9GcdUaGFwgCQhmf0SVhwaKGkdHlwZaNwYXk=";
+  let signed_bytes = base64_decode(golden).unwrap();
+
+  match peek_tx_type(&signed_bytes).unwrap() {
+    TxType::Payment => {},
+    other => panic!("Unexpected transaction type {:?}", other),
+  }
+
+  assert!(peek_tx_type(&signed_bytes[..20]).is_err());
+}
+
+#[test]
+fn test_decoded_signed_transaction_fields() {
+  let golden = "gqNzaWfEQPhUAZ3xkDDcc8FvOVo6UinzmKBCqs0woYSfodlmBMfQvGbeUx3Srxy3dyJDzv7rLm26BRv9FnL2/AuT7NYfiAWjdHhui6NhbXTNA+ilY2xvc2XEIEDpNJKIJWTLzpxZpptnVCaJ6aHDoqnqW2Wm6KRCH/xXo2ZlZc0EmKJmds0wsqNnZW6sZGV2bmV0LXYzMy4womdoxCAmCyAJoJOohot5WHIvpeVG7eftF+TYXEx4r7BFJpDt0qJsds00mqRub3RlxAjqABVHQ2y/lqNyY3bEIHts4k/rW6zAsWTinCIsV/X2PcOH1DkEglhBHF/hD3wCo3NuZMQg5/D4TQaBHfnzHI2HixFV9GcdUaGFwgCQhmf0SVhwaKGkdHlwZaNwYXk=";
+  let signed_txn = SignedTransaction::decode_and_verify(&base64_decode(golden).unwrap()).unwrap();
+  let receiver = Address::from_string("PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI").unwrap();
+
+  let raw_txn = signed_txn.transaction();
+  assert_eq!(raw_txn.tx_type, TxType::Payment);
+  assert_eq!(raw_txn.amount, Some(MicroAlgos(1000)));
+  assert_eq!(raw_txn.receiver.as_ref().unwrap().as_ref(), receiver.as_bytes());
+
+  assert_eq!(raw_txn.sender_address().unwrap().to_string(), "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU");
+  assert_eq!(raw_txn.receiver_address().unwrap(), Some(receiver));
+}
+
+#[test]
+fn test_raw_transaction_addresses_are_validated() {
+  let account = Account::generate();
+  let mut raw_txn = Transaction::from_input(AssetTransferTransactionInput::opt_in(&account.address.to_string(), 1234, SuggestedParams {
+    fee: MicroAlgos(1000),
+    first_round: 322575,
+    last_round: 323575,
+    genesis_id: "".into(),
+    genesis_hash: "SGO1GKSzyE7IEPItTxCByw9x8FmnrCDexi9/cOUJOiI=".into(),
+    is_flat_fee: true,
+    min_fee: MINIMUM_TX_FEE,
+  })).unwrap().to_raw();
+  assert_eq!(raw_txn.sender_address().unwrap(), account.address);
+  assert_eq!(raw_txn.receiver_address().unwrap(), None);
+
+  raw_txn.sender = ByteBuf::from(vec![1; 31]);
+  let actual_error = raw_txn.sender_address().unwrap_err();
+  match actual_error {
+    AlgorandSdkError::WrongAddressByteLength(32, 31) => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_set_group_id() {
+  let account = Account::generate();
+  let receiver = Account::generate();
+  let mut txn = Transaction::from_input(payment_input(&account, &receiver, 1000)).unwrap();
+  assert_eq!(txn.group_id(), None);
+
+  let group_id = [7; 32];
+  txn.set_group_id(group_id);
+  assert_eq!(txn.group_id(), Some(group_id));
+  assert_eq!(txn.to_raw().group.unwrap().as_ref(), &group_id[..]);
+
+  // grp key followed by a 32 byte bin header and the group id
+  let mut expected_encoding: Vec<u8> = vec![0xa3, b'g', b'r', b'p', 0xc4, 32];
+  expected_encoding.extend(&group_id);
+  let raw_bytes = txn.to_raw_bytes().unwrap();
+  assert!(raw_bytes.windows(expected_encoding.len()).any(|window| window == expected_encoding.as_slice()));
+}
+
+#[test]
+fn test_transaction_debug_shows_addresses() {
+  let sender = Account::generate();
+  let receiver = Account::generate();
+  let mut input = payment_input(&sender, &receiver, 1000);
+  input.close_remainder_to = Some(receiver.address.to_string());
+  let txn = Transaction::from_input(input).unwrap();
+
+  let debug = format!("{:?}", txn);
+  assert!(debug.starts_with("Transaction { tx_type: \"pay\""));
+  assert!(debug.contains(&format!("sender: \"{}\"", sender.address.to_string())));
+  assert!(debug.contains(&format!("receiver: \"{}\"", receiver.address.to_string())));
+  assert!(debug.contains(&format!("close_remainder_to: Some(\"{}\")", receiver.address.to_string())));
+  assert!(debug.contains("amount: MicroAlgos(1000)"));
+}
+
+#[test]
+fn test_account_sign_group() {
+  let sender = Account::generate();
+  let receiver = Account::generate();
+  let txns = assign_group_id(vec![
+    Transaction::from_input(payment_input(&sender, &receiver, 1000)).unwrap(),
+    Transaction::from_input(payment_input(&sender, &receiver, 2000)).unwrap(),
+  ]).unwrap();
+
+  let signed_txns = sender.sign_group(&txns).unwrap();
+  assert_eq!(signed_txns.len(), 2);
+  for (signed_txn, txn) in signed_txns.iter().zip(txns.iter()) {
+    assert_eq!(signed_txn.txn_id, txn.id().unwrap());
+    assert_eq!(signed_txn.group_id().unwrap(), &txns[0].group_id().unwrap()[..]);
+    assert!(SignedTransaction::decode_and_verify(&signed_txn.encode().unwrap()).is_ok());
+  }
+}
+
+#[test]
+fn test_account_sign_group_fails_without_same_group_id() {
+  let sender = Account::generate();
+  let receiver = Account::generate();
+  let mut txns = assign_group_id(vec![
+    Transaction::from_input(payment_input(&sender, &receiver, 1000)).unwrap(),
+    Transaction::from_input(payment_input(&sender, &receiver, 2000)).unwrap(),
+  ]).unwrap();
+  let unassigned_txn = Transaction::from_input(payment_input(&sender, &receiver, 3000)).unwrap();
+
+  let mut mismatched_txns = txns.clone();
+  mismatched_txns[1].set_group_id([7; 32]);
+  txns.push(unassigned_txn);
+
+  for invalid_txns in [txns, mismatched_txns] {
+    let actual_error = sender.sign_group(&invalid_txns).unwrap_err();
+    match actual_error {
+      AlgorandSdkError::MismatchedGroupId() => {},
+      other => panic!("Unexpected error {:?}", other),
+    }
+  }
+
+  match sender.sign_group(&[]).unwrap_err() {
+    AlgorandSdkError::EmptyTransactionGroup() => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+fn multisig_signed_payment(signers: &[bool]) -> SignedTransaction {
+  let accounts: Vec<Account> = signers.iter().map(|_| Account::generate()).collect();
+  let public_keys: Vec<PublicKey> = accounts.iter()
+    .map(|account| PublicKey::from_bytes(account.address.as_bytes()).unwrap())
+    .collect();
+  let multisig_account = MultisigAccount::new(1, 2, public_keys).unwrap();
+
+  let mut input = payment_input(&accounts[0], &accounts[1], 1000);
+  input.from = multisig_account.address().to_string();
+  let txn = Transaction::from_input(input).unwrap();
+  let bytes_to_sign = txn.to_raw_bytes().unwrap();
+
+  let subsigs = accounts.iter().zip(signers)
+    .map(|(account, signs)| MultisigSubsig {
+      key: ByteBuf::from(account.address.to_vec()),
+      signature: if *signs { Some(ByteBuf::from(account.sign(&bytes_to_sign).to_bytes().to_vec())) } else { None },
+    })
+    .collect();
+
+  SignedTransaction {
+    multisig_sig: Some(MultisigSig {
+      subsigs,
+      threshold: 2,
+      version: 1,
+    }),
+    auth_address: None,
+    signature: None,
+    transaction: txn.to_raw(),
+    txn_id: txn.id().unwrap(),
+  }
+}
+
+#[test]
+fn test_verify_multisig_works() {
+  let signed_txn = multisig_signed_payment(&[true, false, true]);
+
+  assert!(signed_txn.verify_multisig().unwrap());
+}
+
+#[test]
+fn test_verify_multisig_fails_under_threshold() {
+  let signed_txn = multisig_signed_payment(&[false, true, false]);
+
+  assert!(!signed_txn.verify_multisig().unwrap());
+}
+
+#[test]
+fn test_verify_multisig_fails_without_multisig() {
+  let account = Account::generate();
+  let signed_txn = Transaction::from_input(payment_input(&account, &Account::generate(), 1000)).unwrap()
+    .sign(&account).unwrap();
+
+  match signed_txn.verify_multisig().unwrap_err() {
+    AlgorandSdkError::NotMultisigSigned() => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_verify_multisig_fails_for_wrong_sender() {
+  let mut signed_txn = multisig_signed_payment(&[true, true, true]);
+  signed_txn.transaction.sender = ByteBuf::from(Account::generate().address.to_vec());
+
+  assert!(!signed_txn.verify_multisig().unwrap());
+}
+
+#[test]
+fn test_multisig_sig_encode_decode() {
+  let signed_txn = multisig_signed_payment(&[true, false, true]);
+  let multisig_sig = signed_txn.multisig_sig.unwrap();
+
+  let bytes = multisig_sig.encode().unwrap();
+  let decoded_multisig_sig = MultisigSig::decode(&bytes).unwrap();
+
+  assert_eq!(decoded_multisig_sig.threshold, 2);
+  assert_eq!(decoded_multisig_sig.version, 1);
+  assert_eq!(decoded_multisig_sig.subsigs.len(), 3);
+  assert!(decoded_multisig_sig.subsigs[1].signature.is_none());
+  for (decoded_subsig, subsig) in decoded_multisig_sig.subsigs.iter().zip(multisig_sig.subsigs.iter()) {
+    assert_eq!(decoded_subsig.key, subsig.key);
+    assert_eq!(decoded_subsig.signature, subsig.signature);
+  }
+  assert_eq!(decoded_multisig_sig.encode().unwrap(), bytes);
+}
+
+#[test]
+fn test_sign_multisig_places_signature_at_signer_index() {
+  let accounts: Vec<Account> = (0..3).map(|_| Account::generate()).collect();
+  let public_keys: Vec<PublicKey> = accounts.iter().map(|account| account.public_key()).collect();
+  let multisig_account = MultisigAccount::new(1, 1, public_keys).unwrap();
+
+  let mut input = payment_input(&accounts[0], &accounts[1], 1000);
+  input.from = multisig_account.address().to_string();
+  let txn = Transaction::from_input(input).unwrap();
+
+  let signed_txn = txn.sign_multisig(&multisig_account, &accounts[1]).unwrap();
+  let subsigs = &signed_txn.multisig_sig.as_ref().unwrap().subsigs;
+  assert!(subsigs[0].signature.is_none());
+  assert!(subsigs[1].signature.is_some());
+  assert!(subsigs[2].signature.is_none());
+  assert!(signed_txn.verify_multisig().unwrap());
+
+  let outsider = Account::generate();
+  let actual_error = txn.sign_multisig(&multisig_account, &outsider).err().unwrap();
+  match actual_error {
+    AlgorandSdkError::NotMultisigParticipant(address) => assert_eq!(address, outsider.address.to_string()),
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_append_multisig_signature() {
+  let accounts: Vec<Account> = (0..2).map(|_| Account::generate()).collect();
+  let public_keys: Vec<PublicKey> = accounts.iter().map(|account| account.public_key()).collect();
+  let multisig_account = MultisigAccount::new(1, 2, public_keys).unwrap();
+
+  let mut input = payment_input(&accounts[0], &accounts[1], 1000);
+  input.from = multisig_account.address().to_string();
+  let txn = Transaction::from_input(input).unwrap();
+
+  // the transaction is passed to each signer without any signature yet
+  let mut signed_txn = SignedTransaction {
+    multisig_sig: Some(MultisigSig {
+      subsigs: accounts.iter()
+        .map(|account| MultisigSubsig { key: ByteBuf::from(account.address.to_vec()), signature: None })
+        .collect(),
+      threshold: 2,
+      version: 1,
+    }),
+    auth_address: None,
+    signature: None,
+    transaction: txn.to_raw(),
+    txn_id: txn.id().unwrap(),
+  };
+
+  signed_txn.append_multisig_signature(&accounts[0]).unwrap();
+  assert!(!signed_txn.verify_multisig().unwrap());
+  signed_txn.append_multisig_signature(&accounts[1]).unwrap();
+  assert!(signed_txn.verify_multisig().unwrap());
+  assert!(SignedTransaction::decode_and_verify(&signed_txn.encode().unwrap()).is_ok());
+
+  match signed_txn.append_multisig_signature(&accounts[1]).unwrap_err() {
+    AlgorandSdkError::MultisigAlreadySigned(address) => assert_eq!(address, accounts[1].address.to_string()),
+    other => panic!("Unexpected error {:?}", other),
+  }
+  match signed_txn.append_multisig_signature(&Account::generate()).unwrap_err() {
+    AlgorandSdkError::NotMultisigParticipant(_) => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+
+  let mut single_signed_txn = txn.sign(&accounts[0]).unwrap();
+  match single_signed_txn.append_multisig_signature(&accounts[0]).unwrap_err() {
+    AlgorandSdkError::NotMultisigSigned() => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_decode_and_verify() {
+  let sender = Account::generate();
+  let receiver = Account::generate();
+  let txn = Transaction::from_input(payment_input(&sender, &receiver, 1000)).unwrap();
+  let signed_txn = txn.sign(&sender).unwrap();
+  let bytes = signed_txn.encode().unwrap();
+
+  let decoded_txn = SignedTransaction::decode_and_verify(&bytes).unwrap();
+  assert_eq!(decoded_txn.txn_id, signed_txn.txn_id);
+  assert_eq!(decoded_txn.encode().unwrap(), bytes);
+
+  let multisig_bytes = multisig_signed_payment(&[true, true, false]).encode().unwrap();
+  assert!(SignedTransaction::decode_and_verify(&multisig_bytes).is_ok());
+
+  let actual_error = SignedTransaction::decode_and_verify(&bytes[..bytes.len() - 5]).unwrap_err();
+  match actual_error {
+    AlgorandSdkError::InvalidSignedTransactionEncoding(_) => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+
+  let mut badly_signed_txn = txn.sign(&sender).unwrap();
+  badly_signed_txn.signature = txn.sign(&receiver).unwrap().signature;
+  let actual_error = SignedTransaction::decode_and_verify(&badly_signed_txn.encode().unwrap()).unwrap_err();
+  match actual_error {
+    AlgorandSdkError::InvalidTransactionSignature(txn_id) => assert_eq!(txn_id, signed_txn.txn_id),
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_sign_unsigned_bytes_externally() {
+  let sender = Account::generate();
+  let receiver = Account::generate();
+  let txn = Transaction::from_input(payment_input(&sender, &receiver, 1000)).unwrap();
+
+  let unsigned_bytes = txn.to_unsigned_bytes().unwrap();
+  let signature = sender.sign(&unsigned_bytes);
+  let signed_txn = SignedTransaction::from_unsigned_and_signature(&unsigned_bytes, signature).unwrap();
+
+  let expected_signed_txn = txn.sign(&sender).unwrap();
+  assert_eq!(signed_txn.txn_id, expected_signed_txn.txn_id);
+  assert_eq!(signed_txn.encode().unwrap(), expected_signed_txn.encode().unwrap());
+
+  let wrong_signature = receiver.sign(&unsigned_bytes);
+  let actual_error = SignedTransaction::from_unsigned_and_signature(&unsigned_bytes, wrong_signature).unwrap_err();
+  match actual_error {
+    AlgorandSdkError::InvalidTransactionSignature(_) => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_signed_transaction_file_round_trip() {
+  let sender = Account::generate();
+  let receiver = Account::generate();
+  let signed_txn = Transaction::from_input(payment_input(&sender, &receiver, 1000)).unwrap().sign(&sender).unwrap();
+
+  let path = std::env::temp_dir().join(format!("{}.txn", signed_txn.txn_id));
+  signed_txn.write_to(&path).unwrap();
+  let read_txn = SignedTransaction::read_from(&path).unwrap();
+  assert_eq!(read_txn.encode().unwrap(), signed_txn.encode().unwrap());
+  assert_eq!(read_txn.txn_id, signed_txn.txn_id);
+
+  // a group file has to be read with read_all_from
+  SignedTransaction::write_all_to(&[signed_txn, read_txn], &path).unwrap();
+  let actual_error = SignedTransaction::read_from(&path).unwrap_err();
+  std::fs::remove_file(&path).unwrap();
+  match actual_error {
+    AlgorandSdkError::NotSingleSignedTransaction(2) => {},
+    other => panic!("Unexpected error {:?}", other),
+  }
+}
+
+#[test]
+fn test_signed_transactions_file_round_trip() {
+  let sender = Account::generate();
+  let receiver = Account::generate();
+  let signed_txns = vec![
+    Transaction::from_input(payment_input(&sender, &receiver, 1000)).unwrap().sign(&sender).unwrap(),
+    Transaction::from_input(payment_input(&sender, &receiver, 2000)).unwrap().sign(&sender).unwrap(),
+  ];
+
+  let path = std::env::temp_dir().join(format!("{}.stxn", signed_txns[0].txn_id));
+  SignedTransaction::write_all_to(&signed_txns, &path).unwrap();
+  let read_txns = SignedTransaction::read_all_from(&path).unwrap();
+  std::fs::remove_file(&path).unwrap();
+
+  assert_eq!(read_txns.len(), 2);
+  for (read_txn, signed_txn) in read_txns.iter().zip(signed_txns.iter()) {
+    assert_eq!(read_txn.encode().unwrap(), signed_txn.encode().unwrap());
+    assert_eq!(read_txn.txn_id, signed_txn.txn_id);
+  }
+}
@@ -1,21 +1,35 @@
 use std::fmt;
+use std::str::FromStr;
 use serde_bytes::ByteBuf;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use super::asset::{AssetID, AssetParams};
-use crate::accounts::{PublicKeyBytes, AddressBytes};
+use super::micro_algos::MicroAlgos;
+use crate::accounts::{Address, PublicKeyBytes, AddressBytes};
 use crate::errors::{Error, AlgorandSdkError};
 
+/// Byte length of sha512/256 digests such as the genesis hash, group id and transaction id
 pub const DIGEST_BYTE_LENGTH: usize = 32;
+pub const STATE_PROOF_PK_BYTE_LENGTH: usize = 64;
 
 /// Indentifies the type of the transaction
-#[derive(Clone, Copy, Debug)]
+/// 
+/// # Example
+/// ```rust
+/// use rust_algorand_sdk::transaction::TxType;
+/// 
+/// let tx_type: TxType = "pay".parse().unwrap();
+/// assert_eq!(tx_type.to_string(), "pay");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum TxType {
   // Type for Payment Transactions
   Payment,
   // Type for Key registrations
   KeyReg,
   // Type for transaction that creates, re-configures or destroys an asset
-  AssetConfig
+  AssetConfig,
+  // Type for transaction that transfers, opts in to or revokes an asset
+  AssetTransfer,
 }
 
 impl TxType {
@@ -24,6 +38,7 @@ impl TxType {
       "pay" => Ok(TxType::Payment),
       "keyreg" => Ok(TxType::KeyReg),
       "acfg" => Ok(TxType::AssetConfig),
+      "axfer" => Ok(TxType::AssetTransfer),
       others => Err(AlgorandSdkError::GenericError(format!("Unknown transaction type {}", others)))?,
     }
   }
@@ -33,10 +48,25 @@ impl TxType {
       TxType::Payment => "pay",
       TxType::KeyReg => "keyreg",
       TxType::AssetConfig => "acfg",
+      TxType::AssetTransfer => "axfer",
     }
   }
 }
 
+impl FromStr for TxType {
+  type Err = Error;
+
+  fn from_str(tx_type: &str) -> Result<TxType, Error> {
+    TxType::from_str(tx_type)
+  }
+}
+
+impl fmt::Display for TxType {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_str(self.to_str())
+  }
+}
+
 struct TxTypeStringVisitor;
 
 impl<'de> de::Visitor<'de> for TxTypeStringVisitor {
@@ -66,19 +96,35 @@ where D: Deserializer<'de>
   deserializer.deserialize_str(TxTypeStringVisitor)
 }
 
-pub type MicroAlgos = u64;
-pub type Round = u64;
+fn is_false(value: &bool) -> bool {
+  !*value
+}
 
-/// This is for internal use only. Primarily for encoding and sending over the network
-#[derive(Debug, Deserialize, Serialize)]
+/// The encoded form of a transaction, used for sending it over the network.
+///
+/// Its fields are named after the msgpack keys and hold addresses as raw bytes.
+/// Decoded signed transactions expose it with `SignedTransaction::transaction()`
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct RawTransaction {
   // NOTE: All fields should be in alphabetical order for encoding to work properly
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub aamt: Option<u64>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub aclose: Option<ByteBuf>,
+
   #[serde(rename = "amt", skip_serializing_if = "Option::is_none")]
   pub amount: Option<MicroAlgos>,
 
   #[serde(rename = "apar", skip_serializing_if = "Option::is_none")]
   pub asset_params: Option<AssetParams>,
 
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub arcv: Option<ByteBuf>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub asnd: Option<ByteBuf>,
+
   #[serde(rename = "caid", skip_serializing_if = "Option::is_none")]
   pub asset_id: Option<AssetID>,
 
@@ -91,9 +137,9 @@ pub struct RawTransaction {
   pub fee: MicroAlgos,
 
   #[serde(rename = "fv")]
-  pub first_valid: Round,
+  pub first_valid: u64,
 
-  #[serde(rename = "gen", skip_serializing_if = "String::is_empty")]
+  #[serde(rename = "gen", default, skip_serializing_if = "String::is_empty")]
   pub genesis_id: String,
 
   #[serde(rename = "gh")]
@@ -105,7 +151,11 @@ pub struct RawTransaction {
   pub group: Option<ByteBuf>,
 
   #[serde(rename = "lv")]
-  pub last_valid: Round,
+  pub last_valid: u64,
+
+  /// Marks the account as non-participating. Only encoded when true
+  #[serde(default, skip_serializing_if = "is_false")]
+  pub nonpart: bool,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   pub note: Option<ByteBuf>,
@@ -119,11 +169,14 @@ pub struct RawTransaction {
   #[serde(rename = "snd")]
   pub sender: ByteBuf,
 
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sprfkey: Option<ByteBuf>,
+
   #[serde(with = "super::tx_type", rename = "type")]
   pub tx_type: TxType,
   
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub votefst: Option<Round>,
+  pub votefst: Option<u64>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
   pub votekd: Option<u64>,
@@ -132,15 +185,37 @@ pub struct RawTransaction {
   pub votekey: Option<ByteBuf>,
 
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub votelst: Option<Round>,
+  pub votelst: Option<u64>,
+
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub xaid: Option<u64>,
+}
+
+impl RawTransaction {
+  /// Returns the sender as an `Address`.
+  /// 
+  /// It will return an Error if the sender is not 32 bytes, such as in a malformed decoded transaction
+  pub fn sender_address(&self) -> Result<Address, Error> {
+    Address::from_bytes(&self.sender)
+  }
+
+  /// Returns the receiver of a payment as an `Address`, None for other transactions.
+  /// 
+  /// It will return an Error if the receiver is not 32 bytes
+  pub fn receiver_address(&self) -> Result<Option<Address>, Error> {
+    match self.receiver {
+      Some(ref receiver) => Ok(Some(Address::from_bytes(receiver)?)),
+      None => Ok(None),
+    }
+  }
 }
 
 #[derive(Clone, Debug)]
 pub struct TransactionHeader {
   pub sender: AddressBytes,
   pub fee: MicroAlgos,
-  pub first_valid: Round,
-  pub last_valid: Round,
+  pub first_valid: u64,
+  pub last_valid: u64,
   pub note: Option<Vec<u8>>,
   pub genesis_id: String,
   pub genesis_hash: [u8; DIGEST_BYTE_LENGTH],
@@ -165,7 +240,8 @@ pub struct PaymentTransactionParams {
 pub struct KeyRegTransactionParams {
   pub vote_pk: PublicKeyBytes,
   pub selection_pk: PublicKeyBytes,
-  pub vote_first: Round,
-  pub vote_last: Round,
+  pub vote_first: u64,
+  pub vote_last: u64,
   pub vote_key_dilution: u64,
+  pub state_proof_pk: Option<[u8; STATE_PROOF_PK_BYTE_LENGTH]>,
 }
\ No newline at end of file
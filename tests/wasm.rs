@@ -0,0 +1,52 @@
+// Checks account management and signing on wasm32-unknown-unknown. Run it with wasm-bindgen-test-runner:
+// CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER=wasm-bindgen-test-runner \
+//   cargo test --target wasm32-unknown-unknown --no-default-features --features wasm --test wasm
+#![cfg(target_arch = "wasm32")]
+
+use wasm_bindgen_test::wasm_bindgen_test;
+use rust_algorand_sdk::accounts::Account;
+use rust_algorand_sdk::accounts::mnemonics::{mnemonic_from_seed, seed_from_mnemonic};
+use rust_algorand_sdk::encoding::base64_decode;
+use rust_algorand_sdk::transaction::{MicroAlgos, PaymentTransactionInput, Transaction};
+
+const MNEMONIC: &str = "advice pudding treat near rule blouse same whisper inner electric quit surface sunny dismiss leader blood seat clown cost exist hospital century reform able sponsor";
+
+#[wasm_bindgen_test]
+fn test_mnemonic_round_trips() {
+  let account = Account::from_mnemonic(MNEMONIC).unwrap();
+  assert_eq!(account.address.to_string(), "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU");
+
+  let seed = seed_from_mnemonic(MNEMONIC).unwrap();
+  assert_eq!(mnemonic_from_seed(&seed).unwrap(), MNEMONIC);
+}
+
+#[wasm_bindgen_test]
+fn test_generate() {
+  let account = Account::try_generate().unwrap();
+  assert_ne!(account.address, Account::generate().address);
+}
+
+#[wasm_bindgen_test]
+fn test_sign_payment() {
+  let golden = "gqNzaWfEQPhUAZ3xkDDcc8FvOVo6UinzmKBCqs0woYSfodlmBMfQvGbeUx3Srxy3dyJDzv7rLm26BRv9FnL2/AuT7NYfiAWjdHhui6NhbXTNA+ilY2xvc2XEIEDpNJKIJWTLzpxZpptnVCaJ6aHDoqnqW2Wm6KRCH/xXo2ZlZc0EmKJmds0wsqNnZW6sZGV2bmV0LXYzMy4womdoxCAmCyAJoJOohot5WHIvpeVG7eftF+TYXEx4r7BFJpDt0qJsds00mqRub3RlxAjqABVHQ2y/lqNyY3bEIHts4k/rW6zAsWTinCIsV/X2PcOH1DkEglhBHF/hD3wCo3NuZMQg5/D4TQaBHfnzHI2HixFV9GcdUaGFwgCQhmf0SVhwaKGkdHlwZaNwYXk=";
+  let account = Account::from_mnemonic(MNEMONIC).unwrap();
+
+  let txn = Transaction::from_input(PaymentTransactionInput {
+    from: account.address.to_string(),
+    to: "PNWOET7LLOWMBMLE4KOCELCX6X3D3Q4H2Q4QJASYIEOF7YIPPQBG3YQ5YI".into(),
+    fee: MicroAlgos(4),
+    amount: MicroAlgos(1000),
+    first_round: 12466,
+    last_round: 13466,
+    note: Some(base64_decode("6gAVR0Nsv5Y=").unwrap()),
+    close_remainder_to: Some("IDUTJEUIEVSMXTU4LGTJWZ2UE2E6TIODUKU6UW3FU3UKIQQ77RLUBBBFLA".into()),
+    allow_self_close: false,
+    genesis_id: "devnet-v33.0".into(),
+    genesis_hash: "JgsgCaCTqIaLeVhyL6XlRu3n7Rfk2FxMeK+wRSaQ7dI=".into(),
+    is_flat_fee: false,
+  }).unwrap();
+
+  let signed_txn = txn.sign(&account).unwrap();
+  assert_eq!(signed_txn.encode().unwrap(), base64_decode(golden).unwrap());
+  assert_eq!(signed_txn.txn_id, "5FJDJD5LMZC3EHUYYJNH5I23U4X6H2KXABNDGPIL557ZMJ33GZHQ");
+}
\ No newline at end of file